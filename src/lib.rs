@@ -2,4 +2,25 @@ pub mod modules {
     pub mod pet;
     pub mod food;
     pub mod games;
+    pub mod recipes;
+    pub mod clock;
+    pub mod session;
+    pub mod social;
+    pub mod rng;
+    pub mod genetics;
+    pub mod simulation;
+    pub mod autopilot;
+    pub mod events;
+    pub mod history;
+    pub mod journal;
+    pub mod trends;
+    pub mod cli;
+    pub mod status;
+    pub mod tui;
+    pub mod animation;
+    pub mod skins;
+    pub mod display;
+    pub mod locale;
+    pub mod repl;
+    pub mod rules;
 }
\ No newline at end of file
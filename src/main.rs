@@ -4,81 +4,114 @@ use std::io::{self, Write};
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-
-mod modules 
-{
-    pub mod pet;
-    pub mod food;
-    pub mod games;
-}
-
-use modules::pet::{pets_list, is_dead, Pet, PetKind};
-use modules::food::{initialize_food, get_food_for_pet};
-use modules::games::{initialize_games, get_games, GameEffect};
+use tamagotchi::modules::pet::{pets_list, Pet, PetKind};
+use tamagotchi::modules::food::{initialize_food, get_food_for_pet, FoodEffect, FoodGroup};
+use tamagotchi::modules::games::{initialize_games, get_games, GameEffect};
+use tamagotchi::modules::recipes::{initialize_recipes, get_recipes_for_pet, Recipe};
+use tamagotchi::modules::session::Session;
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::social::{get_shared_games, interact, Friendships, Interaction};
+use tamagotchi::modules::genetics::{breed, FamilyTree};
+use tamagotchi::modules::simulation::Simulation;
+use tamagotchi::modules::autopilot::policy_by_name;
+use tamagotchi::modules::journal::{filter_entries, page, EntryKind, Journal, JOURNAL_DIRECTORY};
+use tamagotchi::modules::trends::{render_trends, Span};
+use tamagotchi::modules::display;
+use tamagotchi::modules::cli::{parse_args, parse_format, run, usage, EXIT_USAGE};
+use tamagotchi::modules::tui::run_tui;
+use tamagotchi::modules::animation::{Animation, DEFAULT_FPS, MAX_FPS};
+use tamagotchi::modules::skins::{remember, skins_list, Skin, SKINS_DIRECTORY};
+use tamagotchi::modules::locale::{food_name, game_name, kind_name, recipe_name};
+use tamagotchi::modules::repl::{fuzzy_find, parse_command, print_help, Action, Repl};
+use tamagotchi::modules::rules::{rules, Difficulty};
+use tamagotchi::modules::animation::KINDS;
+use tamagotchi::t;
 
 
 //Creaating an object of type pet
-fn create_new_pet() -> Pet 
+fn create_new_pet(home: &Home) -> Pet 
 {
-    println!("Available pet kinds:");
+    println!("{}", t!("create.kinds"));
 
-    for kind in &[
-        PetKind::Axalotl,
-        PetKind::Cat,
-        PetKind::Dog,
-        PetKind::Monkey,
-        PetKind::Parrot,
-        PetKind::Rabbit,
-        PetKind::Squirrel,
-    ] {
-        println!(" - {}", kind.to_string());
+    for kind in &KINDS 
+    {
+        println!(" - {}", kind_name(kind));
     }
 
-    print!("Enter your pet's name: ");
+    print!("{} ", t!("create.enter_name"));
     io::stdout().flush().unwrap();
     let mut pet_name = String::new();
     io::stdin().read_line(&mut pet_name).expect("Failed to read input");
     let pet_name = pet_name.trim().to_string();
 
-    print!("Enter your pet's kind: ");
+    print!("{} ", t!("create.enter_kind"));
     io::stdout().flush().unwrap();
     let mut kind_str = String::new();
     io::stdin().read_line(&mut kind_str).expect("Failed to read input");
     let kind_str = kind_str.trim();
 
-    let pet_kind = match PetKind::from_str(kind_str) 
+    //The kind can be typed in English or in the chosen language
+    let pet_kind = match PetKind::from_str(kind_str).or_else(|| KINDS.into_iter().find(|kind| kind_name(kind).to_lowercase() == kind_str.to_lowercase())) 
     {
         Some(k) => k,
         None => 
         {
-            println!("Invalid pet kind, defaulting to cat.");
+            println!("{}", t!("create.invalid_kind"));
             PetKind::Cat
         }
     };
     
-    let new_pet = Pet::new(pet_name, pet_kind);
-    println!("\nYour pet was created!");
-    new_pet.display_stats();
+    println!("{}", t!("create.difficulties"));
+
+    for difficulty in Difficulty::ALL 
+    {
+        println!(" - {}: {}", difficulty.name(), difficulty.description());
+    }
+
+    print!("{} ", t!("create.enter_difficulty"));
+    io::stdout().flush().unwrap();
+    let mut difficulty_str = String::new();
+    io::stdin().read_line(&mut difficulty_str).expect("Failed to read input");
+    let difficulty_str = difficulty_str.trim();
+
+    //Enter keeps the classic game
+    let difficulty = match Difficulty::from_str(difficulty_str).or_else(|| Difficulty::ALL.into_iter().find(|difficulty| difficulty.name().to_lowercase() == difficulty_str.to_lowercase())) 
+    {
+        Some(difficulty) => difficulty,
+        None if difficulty_str.is_empty() => Difficulty::Classic,
+        None => 
+        {
+            println!("{}", t!("create.invalid_difficulty"));
+            Difficulty::Classic
+        }
+    };
+    
+    let mut new_pet = Pet::new(pet_name, pet_kind);
+    new_pet.set_difficulty(difficulty);
+    println!("\n{}", t!("create.created"));
+    new_pet.display_stats(home);
 
     if let Err(e) = new_pet.save_pet_to_file() 
     {
-        println!("Warning: Failed to save pet to file: {}", e);
+        println!("{}", t!("pet.save_failed", error = e));
     }
 
+    Journal::new(JOURNAL_DIRECTORY).write(&new_pet, EntryKind::Created, t!("entry.created", name = new_pet.name(), kind = kind_name(new_pet.kind())));
     new_pet
 }
 
 //Loading pet from the files
-fn load_existing_pet() -> Option<Pet> 
+fn load_existing_pet(home: &Home) -> Option<Pet> 
 {
-    println!("\nSaved pets list:");
+    println!("\n{}", t!("load.saved_pets"));
     if let Ok(names) = pets_list() 
     {
         if names.is_empty() 
         {
-            println!("No pets found.\n");
+            println!("{}\n", t!("load.no_pets"));
             return None;
         }
 
@@ -86,7 +119,7 @@ fn load_existing_pet() -> Option<Pet>
         {
             println!(" - {}", name);
         }
-        print!("Enter the name of the pet to load: ");
+        print!("{} ", t!("load.enter_name"));
         io::stdout().flush().unwrap();
 
         let mut pet_name = String::new();
@@ -97,34 +130,35 @@ fn load_existing_pet() -> Option<Pet>
         {
             Ok(pet) => 
             {
-                println!("Pet loaded successfully!\n");
-                pet.display_stats();
+                println!("{}\n", t!("load.loaded"));
+                pet.display_stats(home);
+                Journal::new(JOURNAL_DIRECTORY).write(&pet, EntryKind::Load, t!("entry.loaded"));
                 Some(pet)
             }
             Err(e) => 
             {
-                println!("Could not load pet: {}\n", e);
+                println!("{}\n", t!("load.failed", error = e));
                 None
             }
         }
     } 
     else 
     {
-        println!("Failed to read saved pets directory.\n");
+        println!("{}\n", t!("load.no_directory"));
         None
     }
 }
 
 
-fn select_pet() -> Option<Pet> 
+fn select_pet(home: &Home) -> Option<Pet> 
 {
     loop 
     {
-        println!("\n=== PET SELECTION ===\n");
-        println!("1) Create New Pet\n");
-        println!("2) Load Existing Pet\n");
-        println!("3) Go Back to Main Menu\n");
-        print!("Choose an option: ");
+        println!("\n=== {} ===\n", t!("select.title"));
+        println!("1) {}\n", t!("select.create"));
+        println!("2) {}\n", t!("select.load"));
+        println!("3) {}\n", t!("select.back"));
+        print!("{} ", t!("menu.choose_option"));
         io::stdout().flush().unwrap();
 
         let mut selection = String::new();
@@ -135,12 +169,12 @@ fn select_pet() -> Option<Pet>
         {
             "1" => 
             {
-                let new_pet = create_new_pet();
+                let new_pet = create_new_pet(home);
                 return Some(new_pet);
             }
             "2" => 
             {
-                let loaded = load_existing_pet();
+                let loaded = load_existing_pet(home);
                 if loaded.is_some() 
                 {
                     return loaded;
@@ -152,109 +186,241 @@ fn select_pet() -> Option<Pet>
             }
             _ => 
             {
-                println!("Invalid choice, please try again.\n");
+                println!("{}\n", t!("menu.invalid_choice"));
             }
         }
     }
 }
 
-//Feeding pet
-fn feed_pet(pet: &mut Pet, food_map: &HashMap<String, (u8, u8)>) 
+//Reads a line, e.g. the name of a food
+fn read_input(prompt: &str) -> String 
+{
+    print!("{} ", prompt);
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).expect("Failed to read input");
+    input.trim().to_string()
+}
+
+//The name the player meant, even if misspelled. When it could be several, they are listed
+//and None is returned. Names that fit nothing are passed on for the pet to turn down.
+fn resolve_name(input: &str, names: &[String], translated: impl Fn(&str) -> String) -> Option<String> 
+{
+    match fuzzy_find(input, names, &translated) 
+    {
+        Ok(name) => Some(name),
+        Err(suggestions) if !suggestions.is_empty() => 
+        {
+            let suggestions: Vec<String> = suggestions.iter().map(|name| translated(name)).collect();
+            println!("{}", t!("repl.did_you_mean", names = suggestions.join(", ")));
+            None
+        }
+        Err(_) => Some(input.trim().to_string()),
+    }
+}
+
+//Feeding pet, with the food typed in the command or chosen from the list
+fn feed_pet(pet: &mut Pet, food_map: &HashMap<String, FoodEffect>, choice: Option<String>) -> bool 
 {
-    println!("\nFoods available for {}:", pet.kind().to_string());
     let valid_foods = get_food_for_pet(pet.kind());
+    let choice = match choice 
+    {
+        Some(choice) => choice,
+        None => 
+        {
+            list_foods(pet, food_map, &valid_foods);
+            read_input(&t!("food.enter_name"))
+        }
+    };
 
-    for f in &valid_foods 
+    let success = match resolve_name(&choice, &valid_foods, food_name) 
     {
-        if let Some(&(sat, en)) = food_map.get(f) 
+        Some(food) => pet.feed(&food, food_map),
+        None => false,
+    };
+
+    if !success 
+    {
+        println!("{}", t!("food.failed"));
+    }
+    success
+}
+
+fn list_foods(pet: &Pet, food_map: &HashMap<String, FoodEffect>, valid_foods: &[String]) 
+{
+    println!("\n{}", t!("food.available", kind = kind_name(pet.kind())));
+
+    for f in valid_foods 
+    {
+        if let Some(food) = food_map.get(f) 
         {
-            println!(" - {} (Satiation +{}, Energy +{})", f, sat, en);
+            let group = t!(match food.group 
+            {
+                FoodGroup::Protein => "group.protein",
+                FoodGroup::FruitVeg => "group.fruit_veg",
+                FoodGroup::Treat => "group.treat",
+            });
+            println!(" - {}", t!("food.group_effect", food = food_name(f), group = group, satiation = food.satiation_gain, energy = food.energy_gain));
         } 
         else 
         {
-            println!(" - {}", f);
+            println!(" - {}", food_name(f));
         }
     }
+}
 
-    print!("Enter the name of the food: ");
-    io::stdout().flush().unwrap();
-
-    let mut food_choice = String::new();
-    io::stdin().read_line(&mut food_choice).expect("Failed to read input");
-    let food_choice = food_choice.trim();
+//Playing with the pet, with the game typed in the command or chosen from the list
+fn play_with_pet(pet: &mut Pet, games_map: &HashMap<String, GameEffect>, home: &Home, choice: Option<String>) -> bool 
+{
+    let valid_games = get_games(pet.kind());
+    let choice = match choice 
+    {
+        Some(choice) => choice,
+        None => 
+        {
+            list_games(pet, games_map, &valid_games);
+            read_input(&t!("game.enter_name"))
+        }
+    };
 
-    let success = pet.feed(food_choice, food_map);
-    if !success 
+    match resolve_name(&choice, &valid_games, game_name) 
     {
-        println!("Feeding was unsuccessful.");
+        Some(game) => pet.play(&game, games_map, home),
+        None => false,
     }
 }
 
-//Playing with the pet
-fn play_with_pet(pet: &mut Pet, games_map: &HashMap<String, GameEffect>) -> bool 
+fn list_games(pet: &Pet, games_map: &HashMap<String, GameEffect>, valid_games: &[String]) 
 {
-    println!("\nGames available for {}:", pet.kind().to_string());
-    let valid_games = get_games(pet.kind());
+    println!("\n{}", t!("game.available", kind = kind_name(pet.kind())));
 
-    for g in &valid_games 
+    for g in valid_games 
     {
         if let Some(game) = games_map.get(g) 
         {
-            println!(
-                " - {} (Happiness +{}, Energy -{}, Satiation -{})",
-                g, game.happiness_increase, game.energy_cost, game.satiation_cost
-            );
+            let effect = t!("game.effect", game = game_name(g), happiness = game.happiness_increase, energy = game.energy_cost, satiation = game.satiation_cost);
+
+            if game.daytime_only 
+            {
+                println!(" - {}", t!("game.daytime_only", effect = effect));
+            } 
+            else 
+            {
+                println!(" - {}", effect);
+            }
         } 
         else 
         {
-            println!(" - {}", g);
+            println!(" - {}", game_name(g));
         }
     }
+}
 
-    print!("Enter the name of the game: ");
-    io::stdout().flush().unwrap();
+//Cooking for the pet, with the recipe typed in the command or chosen from the list
+fn cook_for_pet(pet: &mut Pet, recipes_map: &HashMap<String, Recipe>, food_map: &HashMap<String, FoodEffect>, choice: Option<String>) -> bool 
+{
+    let valid_recipes = get_recipes_for_pet(pet.kind(), recipes_map);
+    let choice = match choice 
+    {
+        Some(choice) => choice,
+        None => 
+        {
+            list_recipes(pet, recipes_map, food_map, &valid_recipes);
+            read_input(&t!("recipe.enter_name"))
+        }
+    };
 
-    let mut game_choice = String::new();
-    io::stdin().read_line(&mut game_choice).expect("Failed to read input");
-    let game_choice = game_choice.trim();
+    let success = match resolve_name(&choice, &valid_recipes, recipe_name) 
+    {
+        Some(recipe) => pet.cook(&recipe, recipes_map, food_map),
+        None => false,
+    };
 
-    let success = pet.play(game_choice, games_map);
-    
+    if !success 
+    {
+        println!("{}", t!("recipe.failed"));
+    }
     success
 }
 
+fn list_recipes(pet: &Pet, recipes_map: &HashMap<String, Recipe>, food_map: &HashMap<String, FoodEffect>, valid_recipes: &[String]) 
+{
+    println!("\n{}", t!("recipe.available", kind = kind_name(pet.kind())));
+
+    for r in valid_recipes 
+    {
+        let recipe = &recipes_map[r];
+        let ingredients = recipe.ingredients.iter().map(|ingredient| food_name(ingredient)).collect::<Vec<_>>().join(" + ");
+        
+        if let Some(dish) = recipe.effect(food_map) 
+        {
+            println!(" - {}", t!("recipe.effect", recipe = recipe_name(r), ingredients = ingredients, satiation = dish.satiation_gain, energy = dish.energy_gain));
+        } 
+        else 
+        {
+            println!(" - {} = {}", recipe_name(r), ingredients);
+        }
+    }
+
+    //What is left in the kitchen today
+    let stock: Vec<String> = get_food_for_pet(pet.kind())
+        .iter()
+        .map(|food_id| format!("{} x{}", food_name(food_id), pet.pantry().get(food_id).copied().unwrap_or(0)))
+        .collect();
+    println!("{}", t!("recipe.kitchen", foods = stock.join(", ")));
+}
+
 
 //Game menu
 pub fn print_game_menu()
 {
     println!("<=+=+=+=+=+=+=+=+=+=+=+=+=+=+=+==+=+=+=>");
-    println!("What would you like to do?");
-    println!("1) Feed");
-    println!("2) Play");
-    println!("3) Put to Sleep");
-    println!("4) Leave Pet Alone");
-    println!("5) Save & Exit");
-    print!("Enter choice: ");
+    println!("{}", t!("menu.question"));
+    println!("1) {}", t!("menu.feed"));
+    println!("2) {}", t!("menu.play"));
+    println!("3) {}", t!("menu.sleep"));
+    println!("4) {}", t!("menu.leave"));
+    println!("5) {}", t!("menu.cook"));
+    println!("6) {}", t!("menu.lights"));
+    println!("7) {}", t!("menu.switch"));
+    println!("8) {}", t!("menu.add"));
+    println!("9) {}", t!("menu.show_all"));
+    println!("10) {}", t!("menu.meet"));
+    println!("11) {}", t!("menu.breed"));
+    println!("12) {}", t!("menu.family_tree"));
+    println!("13) {}", t!("menu.autopilot"));
+    println!("14) {}", t!("menu.session_log"));
+    println!("15) {}", t!("menu.undo"));
+    println!("16) {}", t!("menu.action_history"));
+    println!("17) {}", t!("menu.pet_history"));
+    println!("18) {}", t!("menu.trends"));
+    println!("19) {}", t!("menu.skin"));
+    println!("0) {}", t!("menu.save_exit"));
+    println!("{}", t!("repl.hint"));
 }
 
 
-//Another thread for 30 seconds counting in the console
-fn start_counting(pet: Arc<Mutex<Pet>>, stop_flag: Arc<AtomicBool>) 
+//Another thread counting the ticks (tick_seconds in the rules) in the console. It shares the
+//food, games and recipes with the game menu instead of loading them again.
+fn start_counting(session: Arc<Mutex<Session>>, stop_flag: Arc<AtomicBool>, food_map: Arc<HashMap<String, FoodEffect>>, games_map: Arc<HashMap<String, GameEffect>>, recipes_map: Arc<HashMap<String, Recipe>>) 
 {
     thread::spawn(move || {
+        let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
         while !stop_flag.load(Ordering::Relaxed) 
         {
-            thread::sleep(Duration::from_secs(30));
+            thread::sleep(rules().tick());
 
-            let mut pet = pet.lock().unwrap();
+            let mut session = session.lock().unwrap();
 
-            pet.set_happiness(-1);
-            pet.set_energy(-1);
-            pet.set_satiation(-1);
+            session.run_autopilots(&simulation);
+            session.tick_all();
 
-            pet.display_stats();
+            session.display_summary();
 
-            if pet.lives() == 0 && is_dead(&pet) 
+            session.remove_dead();
+            if session.is_empty() 
             {
                 break;
             }
@@ -263,122 +429,840 @@ fn start_counting(pet: Arc<Mutex<Pet>>, stop_flag: Arc<AtomicBool>)
 }
 
 
+//Leaving the house leaves every pet in the session alone
+fn leave_pets_alone(session: &mut Session, food_map: &HashMap<String, FoodEffect>) 
+{
+    println!("{}", t!("leave.how_long"));
+    let mut hours = String::new();
+
+    io::stdin().read_line(&mut hours).expect("Failed to read input");
+    let hours: u8 = match hours.trim().parse() {
+        Ok(h) => h,
+        Err(_) => {
+            println!("{}", t!("menu.invalid_input"));
+            return;
+        }
+    };
+
+    for pet in session.pets_mut() 
+    {
+        println!("{}", t!("leave.ask_food", name = pet.name()));
+        let mut leave_food = String::new();
+
+        io::stdin().read_line(&mut leave_food).expect("Failed to read input");
+        let leave_food = leave_food.trim().to_lowercase();
+
+        if leave_food == "yes" || leave_food == t!("answer.yes").to_lowercase() 
+        {
+            pet.leave_food_while_gone(food_map);
+        }
+    }
+
+    session.leave_all_alone(hours);
+
+    for pet in session.pets() 
+    {
+        pet.display_stats(session.home());
+    }
+}
+
+
+//Switching which pet receives the actions
+fn switch_pet(session: &mut Session, choice: Option<String>) 
+{
+    let pet_name = match choice 
+    {
+        Some(choice) => choice,
+        None => 
+        {
+            session.display_summary();
+            read_input(&t!("switch.enter_name"))
+        }
+    };
+
+    if session.switch_to(pet_name.trim()) 
+    {
+        let home = session.home().clone();
+        session.active_pet().display_stats(&home);
+    }
+}
+
+
+//Letting the active pet meet another pet of the session
+fn meet_pet(session: &mut Session, games_map: &HashMap<String, GameEffect>) 
+{
+    session.display_summary();
+    print!("{} ", t!("meet.enter_name"));
+    io::stdout().flush().unwrap();
+
+    let mut other_name = String::new();
+    io::stdin().read_line(&mut other_name).expect("Failed to read input");
+
+    let home = session.home().clone();
+    let (pet, other) = match session.active_and(other_name.trim()) 
+    {
+        Some(pair) => pair,
+        None => 
+        {
+            println!("{}", t!("meet.no_pet"));
+            return;
+        }
+    };
+
+    let mut friendships = match Friendships::load() 
+    {
+        Ok(friendships) => friendships,
+        Err(e) => 
+        {
+            println!("{}", t!("meet.read_failed", error = e));
+            Friendships::default()
+        }
+    };
+
+    println!(
+        "{}",
+        t!("meet.friendship", name = pet.name(), other = other.name(), level = friendships.level(pet.name(), other.name()), score = friendships.score(pet.name(), other.name()))
+    );
+    println!("1) {}", t!("meet.play"));
+    println!("2) {}", t!("meet.groom"));
+    println!("3) {}", t!("meet.fight"));
+    print!("{} ", t!("menu.enter_choice"));
+    io::stdout().flush().unwrap();
+
+    let mut choice = String::new();
+    io::stdin().read_line(&mut choice).expect("Failed to read input");
+
+    let interaction = match choice.trim() 
+    {
+        "1" => 
+        {
+            println!("{}", t!("meet.shared_games", name = pet.name(), other = other.name()));
+            let shared_games = get_shared_games(pet.kind(), other.kind(), games_map);
+            for g in &shared_games 
+            {
+                println!(" - {}", game_name(g));
+            }
+
+            let game_choice = read_input(&t!("game.enter_name"));
+            match resolve_name(&game_choice, &shared_games, game_name) 
+            {
+                Some(game) => Interaction::PlayTogether(game),
+                None => return,
+            }
+        }
+        "2" => Interaction::Groom,
+        "3" => Interaction::FightOverFood,
+        _ => 
+        {
+            println!("{}", t!("menu.invalid_choice_back"));
+            return;
+        }
+    };
+
+    pet.wake_early();
+    other.wake_early();
+
+    if interact(pet, other, &interaction, games_map, &home, &mut friendships) 
+    {
+        if let Err(e) = friendships.save() 
+        {
+            println!("{}", t!("meet.save_failed", error = e));
+        }
+    }
+}
+
+
+//Breeding the active pet with another adult pet of the same kind
+fn breed_pets(session: &mut Session) 
+{
+    session.display_summary();
+    print!("{} ", t!("breed.enter_parent"));
+    io::stdout().flush().unwrap();
+
+    let mut other_name = String::new();
+    io::stdin().read_line(&mut other_name).expect("Failed to read input");
+
+    print!("{} ", t!("breed.enter_child"));
+    io::stdout().flush().unwrap();
+
+    let mut child_name = String::new();
+    io::stdin().read_line(&mut child_name).expect("Failed to read input");
+    let child_name = child_name.trim().to_string();
+
+    if child_name.is_empty() || pets_list().unwrap_or_default().contains(&child_name) 
+    {
+        println!("{}", t!("breed.name_taken"));
+        return;
+    }
+
+    let seed = session.rng().next_u64();
+
+    let (pet, other) = match session.active_and(other_name.trim()) 
+    {
+        Some(pair) => pair,
+        None => 
+        {
+            println!("{}", t!("breed.no_parent"));
+            return;
+        }
+    };
+
+    let child = match breed(pet, other, child_name, seed) 
+    {
+        Ok(child) => child,
+        Err(e) => 
+        {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    println!("{}", t!("breed.egg", name = pet.name(), other = other.name(), seed = seed));
+    let parents = t!("breed.laid", name = pet.name(), other = other.name());
+
+    let mut family_tree = FamilyTree::load().unwrap_or_default();
+    family_tree.add_birth(&child, pet, other);
+
+    if let Err(e) = family_tree.save() 
+    {
+        println!("{}", t!("breed.tree_save_failed", error = e));
+    }
+
+    if let Err(e) = child.save_pet_to_file() 
+    {
+        println!("{}", t!("pet.save_failed", error = e));
+    }
+
+    session.journal().write(&child, EntryKind::Created, parents);
+    child.display_stats(session.home());
+    session.add_pet(child);
+}
+
+//Browsing the lineage of any pet born in the game
+fn show_family_tree() 
+{
+    let family_tree = match FamilyTree::load() 
+    {
+        Ok(family_tree) => family_tree,
+        Err(e) => 
+        {
+            println!("{}", t!("tree.read_failed", error = e));
+            return;
+        }
+    };
+
+    print!("{} ", t!("tree.enter_name"));
+    io::stdout().flush().unwrap();
+
+    let mut pet_name = String::new();
+    io::stdin().read_line(&mut pet_name).expect("Failed to read input");
+    let pet_name = pet_name.trim();
+
+    if family_tree.lineage(pet_name).is_none() 
+    {
+        println!("{}", t!("tree.no_family", name = pet_name));
+        return;
+    }
+
+    println!("\n{}", t!("tree.ancestors"));
+    family_tree.display_ancestors(pet_name);
+
+    let children = family_tree.children(pet_name);
+    if !children.is_empty() 
+    {
+        println!("{}", t!("tree.children", children = children.join(", ")));
+    }
+    println!();
+}
+
+
+//Switching the autopilot for the active pet
+fn choose_autopilot(session: &mut Session) 
+{
+    println!("{}", t!("autopilot.policies"));
+    println!(" - {}", t!("autopilot.greedy"));
+    println!(" - {}", t!("autopilot.neglectful"));
+    println!(" - {}", t!("autopilot.off"));
+    print!("{} ", t!("autopilot.enter_policy", name = session.active_pet().name()));
+    io::stdout().flush().unwrap();
+
+    let mut policy_name = String::new();
+    io::stdin().read_line(&mut policy_name).expect("Failed to read input");
+    let policy_name = policy_name.trim();
+
+    if policy_name.eq_ignore_ascii_case("off") 
+    {
+        session.set_autopilot(None);
+        return;
+    }
+
+    match policy_by_name(policy_name) 
+    {
+        Some(policy) => session.set_autopilot(Some(policy)),
+        None => println!("{}", t!("autopilot.unknown")),
+    }
+}
+
+
+//Browsing the journal of the active pet, newest entries first
+fn show_pet_history(session: &mut Session) 
+{
+    const PER_PAGE: usize = 10;
+
+    let name = session.active_pet().name().to_string();
+    let entries = match session.journal().read(&name) 
+    {
+        Ok(entries) => entries,
+        Err(e) => 
+        {
+            println!("{}", t!("journal.read_failed", name = name, error = e));
+            return;
+        }
+    };
+
+    print!("{} ", t!("history.enter_kind"));
+    io::stdout().flush().unwrap();
+
+    let mut kind_str = String::new();
+    io::stdin().read_line(&mut kind_str).expect("Failed to read input");
+    let kind_str = kind_str.trim();
+
+    let kind = if kind_str.is_empty() 
+    {
+        None
+    } 
+    else 
+    {
+        match EntryKind::from_str(kind_str) 
+        {
+            Some(kind) => Some(kind),
+            None => 
+            {
+                println!("{}", t!("history.unknown_kind"));
+                return;
+            }
+        }
+    };
+
+    let entries = filter_entries(&entries, kind);
+    if entries.is_empty() 
+    {
+        println!("{}", t!("history.no_entries", name = name));
+        return;
+    }
+
+    let pages = entries.len().div_ceil(PER_PAGE);
+    let mut current = 0;
+
+    loop 
+    {
+        println!("\n{}", t!("history.page", name = name, page = current + 1, pages = pages));
+        for entry in page(&entries, current, PER_PAGE) 
+        {
+            println!(" {}", entry.to_line());
+        }
+
+        if current + 1 >= pages 
+        {
+            println!();
+            break;
+        }
+
+        print!("{} ", t!("history.more"));
+        io::stdout().flush().unwrap();
+
+        let mut next = String::new();
+        io::stdin().read_line(&mut next).expect("Failed to read input");
+
+        if next.trim().eq_ignore_ascii_case("q") 
+        {
+            break;
+        }
+        current += 1;
+    }
+}
+
+
+//Charts of the active pet's stats over the last hour, day or week
+fn show_trends(session: &mut Session) 
+{
+    const WIDTH: usize = 48;
+
+    let name = session.active_pet().name().to_string();
+    let entries = match session.journal().read(&name) 
+    {
+        Ok(entries) => entries,
+        Err(e) => 
+        {
+            println!("{}", t!("journal.read_failed", name = name, error = e));
+            return;
+        }
+    };
+
+    print!("{} ", t!("trends.enter_span"));
+    io::stdout().flush().unwrap();
+
+    let mut span = String::new();
+    io::stdin().read_line(&mut span).expect("Failed to read input");
+
+    let span = match Span::from_str(span.trim()) 
+    {
+        Some(span) => span,
+        None => 
+        {
+            println!("{}", t!("menu.invalid_choice_back"));
+            return;
+        }
+    };
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    println!("\n{}", t!("trends.title", name = name, span = span.name()));
+    for line in render_trends(&entries, now, span, WIDTH, display::settings().mode) 
+    {
+        println!(" {}", line);
+    }
+    println!();
+}
+
+
+//Choosing custom art for the active pet from the skins directory
+fn choose_skin(session: &mut Session) 
+{
+    let home = session.home().clone();
+    let pet = session.active_pet();
+
+    println!("{}", t!("skin.list"));
+    println!(" - {}", t!("skin.default"));
+    for skin in skins_list() 
+    {
+        println!(" - {}", skin);
+    }
+    print!("{} ", t!("skin.enter_name", name = pet.name(), skin = pet.skin().map(str::to_string).unwrap_or_else(|| t!("skin.default"))));
+    io::stdout().flush().unwrap();
+
+    let mut skin_name = String::new();
+    io::stdin().read_line(&mut skin_name).expect("Failed to read input");
+    let skin_name = skin_name.trim();
+
+    if skin_name.eq_ignore_ascii_case("default") || skin_name.to_lowercase() == t!("skin.default").to_lowercase() 
+    {
+        pet.set_skin(None);
+        pet.display_stats(&home);
+        return;
+    }
+
+    match Skin::load(SKINS_DIRECTORY, skin_name) 
+    {
+        Ok(skin) => 
+        {
+            remember(skin);
+            pet.set_skin(Some(skin_name.to_string()));
+            pet.display_stats(&home);
+        }
+        Err(errors) => 
+        {
+            println!("{}", t!("skin.invalid", skin = skin_name));
+            for error in errors 
+            {
+                println!(" - {}", error);
+            }
+        }
+    }
+}
+
+
 //Game engine
-fn game_engine(pet: Arc<Mutex<Pet>>, food_map: &HashMap<String, (u8, u8)>, games_map: &HashMap<String, GameEffect>,) 
+fn game_engine(session: Arc<Mutex<Session>>, food_map: &Arc<HashMap<String, FoodEffect>>, games_map: &Arc<HashMap<String, GameEffect>>, recipes_map: &Arc<HashMap<String, Recipe>>, fps: u32) 
 {
+    let mut repl = match Repl::new() 
+    {
+        Ok(repl) => repl,
+        Err(e) => 
+        {
+            println!("{}", t!("repl.failed", error = e));
+            return;
+        }
+    };
+
     let stop_flag = Arc::new(AtomicBool::new(false));
 
-    let pet_clone = Arc::clone(&pet);
+    let session_clone = Arc::clone(&session);
     let stop_flag_clone = Arc::clone(&stop_flag);
-    start_counting(pet_clone, stop_flag_clone);
+    start_counting(session_clone, stop_flag_clone, Arc::clone(food_map), Arc::clone(games_map), Arc::clone(recipes_map));
+
+    print_game_menu();
 
     loop 
     {
-        print_game_menu();
-        io::stdout().flush().unwrap();
+        //The prompt and the completions are for the pet being looked after
+        let prompt = {
+            let mut session = session.lock().unwrap();
+
+            if session.is_empty() 
+            {
+                println!("{}", t!("game.all_gone"));
+                stop_flag.store(true, Ordering::Relaxed);
+                break;
+            }
+
+            let pet = session.active_pet();
+            repl.set_pet(pet.kind(), get_recipes_for_pet(pet.kind(), recipes_map));
+            format!("{}> ", pet.name())
+        };
+
+        //The input ending (Ctrl-D) saves and exits like the quit command
+        let line = repl.read_line(&prompt).unwrap_or_else(|| Action::Quit.name().to_string());
+
+        let (action, argument) = match parse_command(&line) 
+        {
+            Ok(Some(command)) => (command.action, command.argument),
+            Ok(None) => continue,
+            Err(e) => 
+            {
+                println!("{}", e);
+                continue;
+            }
+        };
+
+        //Adding a pet asks for input, so the session must not be locked meanwhile
+        if action == Action::Add 
+        {
+            let home = session.lock().unwrap().home().clone();
+
+            if let Some(pet) = select_pet(&home) 
+            {
+                session.lock().unwrap().add_pet(pet);
+            }
+            continue;
+        }
+
+        let mut session = session.lock().unwrap();
+
+        if session.is_empty() 
+        {
+            println!("{}", t!("game.all_gone"));
+            stop_flag.store(true, Ordering::Relaxed);
+            break;
+        }
 
-        let mut action = String::new();
-        io::stdin().read_line(&mut action).expect("Failed to read input");
-        let action = action.trim();
+        if matches!(action, Action::Feed | Action::Play | Action::Sleep | Action::Cook | Action::Meet | Action::Breed) && session.active_pet().is_egg() 
+        {
+            println!("{}", t!("game.still_egg", name = session.active_pet().name()));
+            continue;
+        }
+
+        //Actions that change the pets are remembered so they can be undone
+        let before = session.snapshot();
+        let command = match action 
+        {
+            Action::Feed => Some(("command.feed", EntryKind::Feed)),
+            Action::Play => Some(("command.play", EntryKind::Play)),
+            Action::Sleep => Some(("command.sleep", EntryKind::Sleep)),
+            Action::Leave => Some(("command.leave", EntryKind::Leave)),
+            Action::Cook => Some(("command.cook", EntryKind::Cook)),
+            Action::Lights => Some(("command.lights", EntryKind::Lights)),
+            _ => None,
+        }.map(|(id, kind)| (t!(id, name = session.active_pet().name()), kind));
+
+        //Any action except sleeping and leaving wakes a sleeping pet up
+        if matches!(action, Action::Feed | Action::Play | Action::Cook) 
+        {
+            session.active_pet().wake_early();
+        }
 
         match action {
-            "1" => {
-                let mut pet = pet.lock().unwrap();
-                feed_pet(&mut pet, food_map);
-                pet.display_stats();
+            Action::Feed => {
+                let home = session.home().clone();
+                let pet = session.active_pet();
+
+                if feed_pet(pet, food_map, argument) 
+                {
+                    pet.display_animated(&home, Animation::Eating, fps);
+                } 
+                else 
+                {
+                    pet.display_stats(&home);
+                }
             }
-            "2" => {
-                let mut pet = pet.lock().unwrap();
+            Action::Play => {
+                let home = session.home().clone();
+                let pet = session.active_pet();
 
-                if play_with_pet(&mut pet, games_map) 
+                if play_with_pet(pet, games_map, &home, argument) 
                 {
-                    println!("You played with your pet!");
-                    pet.display_stats();
+                    println!("{}", t!("game.played"));
+                    pet.display_animated(&home, Animation::Playing, fps);
                 }
                 
             }
-            "3" => {
-                let mut pet = pet.lock().unwrap();
-                if pet.check_energy() 
+            Action::Sleep => {
+                let home = session.home().clone();
+                let pet = session.active_pet();
+                if pet.is_asleep() 
+                {
+                    println!("{}", t!("sleep.already", name = pet.name()));
+                } 
+                else if pet.check_energy().is_some() 
                 {
                     pet.sleep();
-                    println!("{} is sleeping!", pet.name());
-                    pet.display_stats();
+                    println!("{}", t!("sleep.sleeping", name = pet.name()));
+                    pet.display_animated(&home, Animation::Sleeping, fps);
                 } 
                 else 
                 {
-                    println!("Energy is over 5, can't put {} to sleep right now!", pet.name());
+                    println!("{}", t!("sleep.not_tired", name = pet.name(), energy = pet.rules().tired_energy));
                 }
             }
-            "4" => {
-                let mut pet = pet.lock().unwrap();
-                println!("How many hours will you be gone?");
-                let mut hours = String::new();
-
-                io::stdin().read_line(&mut hours).expect("Failed to read input");
-                let hours: u8 = match hours.trim().parse() {
-                    Ok(h) => h,
-                    Err(_) => {
-                        println!("Invalid input. Returning to menu.");
-                        continue;
-                    }
-                };
-
-                println!("Would you like to leave food for {}? (yes/no)", pet.name());
-                let mut leave_food = String::new();
-
-                io::stdin().read_line(&mut leave_food).expect("Failed to read input");
-                let leave_food = leave_food.trim().to_lowercase();
+            Action::Leave => {
+                leave_pets_alone(&mut session, food_map);
+            }
+            Action::Cook => {
+                let home = session.home().clone();
+                let pet = session.active_pet();
 
-                if leave_food == "yes" 
+                if cook_for_pet(pet, recipes_map, food_map, argument) 
+                {
+                    pet.display_animated(&home, Animation::Eating, fps);
+                } 
+                else 
                 {
-                    pet.leave_food_while_gone(food_map);
+                    pet.display_stats(&home);
                 }
+            }
+            Action::Lights => {
+                session.toggle_lights();
+            }
+            Action::Switch => {
+                switch_pet(&mut session, argument);
+            }
+            Action::Pets => {
+                session.display_summary();
+            }
+            Action::Meet => {
+                meet_pet(&mut session, games_map);
+            }
+            Action::Breed => {
+                breed_pets(&mut session);
+            }
+            Action::FamilyTree => {
+                show_family_tree();
+            }
+            Action::Autopilot => {
+                choose_autopilot(&mut session);
+            }
+            Action::Log => {
+                println!("\n{}", t!("log.title"));
+                for line in session.log() 
+                {
+                    println!(" {}", line);
+                }
+                println!();
+            }
+            Action::Undo => {
+                session.undo();
+            }
+            Action::Actions => {
+                println!("\n{}", t!("history.actions"));
+                for command in session.history().commands() 
+                {
+                    if command.is_undoable() 
+                    {
+                        println!(" - {}", t!("history.undoable", action = command.description));
+                    } 
+                    else 
+                    {
+                        println!(" - {}", command.description);
+                    }
 
-                pet.leave_pet_alone(hours);
-                pet.display_stats();
+                    for change in command.changes() 
+                    {
+                        println!("     {}", change);
+                    }
+                }
+                println!();
+            }
+            Action::History => {
+                show_pet_history(&mut session);
+            }
+            Action::Trends => {
+                show_trends(&mut session);
+            }
+            Action::Skin => {
+                choose_skin(&mut session);
             }
-            "5" | "exit" => {
+            Action::Status => {
+                let home = session.home().clone();
+                session.active_pet().display_stats(&home);
+            }
+            Action::Menu => {
+                print_game_menu();
+            }
+            Action::Help => {
+                match argument.map(|name| parse_command(&name)) 
+                {
+                    Some(Ok(Some(command))) => print_help(Some(command.action)),
+                    Some(Err(e)) => println!("{}", e),
+                    _ => print_help(None),
+                }
+            }
+            Action::Quit => {
                 stop_flag.store(true, Ordering::Relaxed);
-                println!("Saving pet...");
+                println!("{}", t!("save.saving"));
 
-                if let Err(e) = pet.lock().unwrap().save_pet_to_file() 
+                if session.save_all().is_err() 
                 {
-                    println!("Warning: Could not save pet: {}", e);
+                    println!("{}", t!("save.failed"));
                 }
 
-                println!("Exiting to main menu...\n");
+                println!("{}\n", t!("game.exiting"));
                 break;
             }
-            _ => {
-                println!("Invalid choice. Please try again.");
-            }
+            Action::Add => {}
         };
 
-        let mut pet = pet.lock().unwrap();
-        pet.check_satiation();
-        pet.check_energy();
+        if let Some((description, kind)) = &command 
+        {
+            session.journal_changes(*kind, description, &before.pets);
+        }
+
+        session.check_all();
+
+        if let Some((description, _)) = command 
+        {
+            if session.snapshot() != before 
+            {
+                session.record_command(description, before);
+            }
+        }
 
-        if is_dead(&pet) 
+        session.remove_dead();
+        if session.is_empty() 
         {
-            println!("Returning to main menu...");
+            println!("{}", t!("game.returning"));
             stop_flag.store(true, Ordering::Relaxed); 
             break;
         }
     }
+
+    repl.save_history();
+}
+
+
+//Reads a number option like --seed 42 and removes it from the arguments
+fn take_number(args: &mut Vec<String>, option: &str, example: u64) -> Result<Option<u64>, String> 
+{
+    match args.iter().position(|arg| arg == option) 
+    {
+        Some(index) => match args.get(index + 1).map(|value| value.parse::<u64>()) 
+        {
+            Some(Ok(number)) => 
+            {
+                args.drain(index..=index + 1);
+                Ok(Some(number))
+            }
+            _ => Err(t!("cli.needs_number", option = option, example = example)),
+        },
+        None => Ok(None),
+    }
+}
+
+
+//Picking the first pet and starting a session with it
+fn start_session(seed: Option<u64>) -> Option<Session> 
+{
+    let home = match Home::load() 
+    {
+        Ok(home) => home,
+        Err(e) => 
+        {
+            println!("{}", t!("home.load_failed", error = e));
+            Home::default()
+        }
+    };
+    let pet = select_pet(&home)?;
+
+    //--seed starts a sequence over, otherwise the one saved with the pet carries on.
+    //A sequence can only be replayed with --seed from its start.
+    let mut session = match (seed, pet.rng().cloned()) 
+    {
+        (Some(seed), _) => Session::with_seed(pet, seed),
+        (None, Some(rng)) => Session::with_rng(pet, rng),
+        (None, None) => Session::new(pet),
+    };
+
+    let rng = session.rng();
+    if rng.draws() == 0 
+    {
+        println!("{}", t!("session.seed", seed = rng.seed()));
+    } 
+    else 
+    {
+        println!("{}", t!("session.resumed", seed = rng.seed(), draws = rng.draws()));
+    }
+    session.set_home(home);
+    session.set_journal(Journal::new(JOURNAL_DIRECTORY));
+
+    Some(session)
 }
 
 
 fn main() 
 {
-    let food_map = initialize_food();
-    let games_map = initialize_games();
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    //--seed replays a session exactly, --fps sets the animation speed (0 turns it off)
+    let fps = take_number(&mut args, "--fps", DEFAULT_FPS as u64).and_then(|fps| match fps 
+    {
+        Some(fps) if fps > MAX_FPS as u64 => Err(t!("cli.fps_too_high", fps = MAX_FPS)),
+        fps => Ok(fps.map(|fps| fps as u32).unwrap_or(DEFAULT_FPS)),
+    });
+
+    let (seed, fps) = match (take_number(&mut args, "--seed", 42), fps) 
+    {
+        (Ok(seed), Ok(fps)) => (seed, fps),
+        (Err(e), _) | (_, Err(e)) => 
+        {
+            eprintln!("{}", e);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    let format = match parse_format(&mut args) 
+    {
+        Ok(format) => format,
+        Err(e) => 
+        {
+            eprintln!("{}", e);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+
+    //Reads the rules now so that problems with them are reported before anything else
+    rules();
+
+    //Subcommands do one thing and exit, without the menus
+    match parse_args(&args) 
+    {
+        Ok(Some(subcommand)) => std::process::exit(run(subcommand, format)),
+        Ok(None) => {}
+        Err(e) => 
+        {
+            eprintln!("{}\n\n{}", e, usage());
+            std::process::exit(EXIT_USAGE);
+        }
+    }
+
+    let food_map = Arc::new(initialize_food());
+    let games_map = Arc::new(initialize_games());
+    let recipes_map = Arc::new(initialize_recipes());
 
     loop {
-        println!("|===================>| TAMAGOTCHI GAME |<===================|");
-        print!("Choose an option:\n");
-        println!("1) Play");
-        println!("2) Exit");
+        println!("|===================>| {} |<===================|", t!("main.title"));
+        println!("{}", t!("menu.choose_option"));
+        println!("1) {}", t!("main.play"));
+        println!("2) {}", t!("main.full_screen"));
+        println!("3) {}", t!("main.exit"));
         io::stdout().flush().unwrap();
 
         let mut choice = String::new();
@@ -387,18 +1271,38 @@ fn main()
 
         match choice {
             "1" => {
-                if let Some(pet) = select_pet() 
+                if let Some(session) = start_session(seed) 
+                {
+                    let session = Arc::new(Mutex::new(session));
+                    game_engine(session, &food_map, &games_map, &recipes_map, fps);
+                }
+            }
+            "2" => {
+                if let Some(mut session) = start_session(seed) 
                 {
-                    let pet = Arc::new(Mutex::new(pet));
-                    game_engine(pet, &food_map, &games_map);
+                    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
+                    if let Err(e) = run_tui(&mut session, &simulation, fps) 
+                    {
+                        println!("{}", t!("main.full_screen_failed", error = e));
+                    }
+
+                    if !session.is_empty() 
+                    {
+                        println!("{}", t!("save.saving"));
+                        if session.save_all().is_err() 
+                        {
+                            println!("{}", t!("save.failed"));
+                        }
+                    }
                 }
             }
-            "2" | "exit" => {
-                println!("Goodbye!");
+            "3" | "exit" => {
+                println!("{}", t!("main.goodbye"));
                 break;
             }
             _ => {
-                println!("Invalid choice. Please try again.\n");
+                println!("{}\n", t!("menu.invalid_choice"));
             }
         }
     }
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::Duration;
+use crossterm::cursor;
+use crossterm::style::Print;
+use crossterm::terminal::{self, ClearType};
+use crossterm::queue;
+use crate::modules::pet::{Mood, Pet, PetKind};
+
+//Animated ASCII art. The frames of every species are kept in data/animations/<kind>.txt,
+//one section per animation, and are played back in place at a configurable frame rate.
+
+pub const DEFAULT_FPS: u32 = 4;
+pub const MAX_FPS: u32 = 60;
+pub const ANIMATION_LENGTH: Duration = Duration::from_millis(1500); //How long an action is animated
+
+pub const KINDS: [PetKind; 7] = [PetKind::Axalotl, PetKind::Cat, PetKind::Dog, PetKind::Monkey, PetKind::Parrot, PetKind::Rabbit, PetKind::Squirrel];
+
+const ANIMATION_FILES: [(PetKind, &str); 7] = [
+    (PetKind::Axalotl, include_str!("../../data/animations/axolotl.txt")),
+    (PetKind::Cat, include_str!("../../data/animations/cat.txt")),
+    (PetKind::Dog, include_str!("../../data/animations/dog.txt")),
+    (PetKind::Monkey, include_str!("../../data/animations/monkey.txt")),
+    (PetKind::Parrot, include_str!("../../data/animations/parrot.txt")),
+    (PetKind::Rabbit, include_str!("../../data/animations/rabbit.txt")),
+    (PetKind::Squirrel, include_str!("../../data/animations/squirrel.txt")),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Animation
+{
+    Idle,
+    Eating,
+    Playing,
+    Sleeping,
+    Sad,
+}
+
+impl Animation
+{
+    pub const ALL: [Animation; 5] = [Animation::Idle, Animation::Eating, Animation::Playing, Animation::Sleeping, Animation::Sad];
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        Self::ALL.into_iter().find(|animation| animation.name() == s)
+    }
+
+    pub fn name(&self) -> &str
+    {
+        match self
+        {
+            Animation::Idle => "idle",
+            Animation::Eating => "eating",
+            Animation::Playing => "playing",
+            Animation::Sleeping => "sleeping",
+            Animation::Sad => "sad",
+        }
+    }
+
+    //What the pet looks like when nothing is happening to it
+    pub fn for_pet(pet: &Pet) -> Self
+    {
+        match pet.mood()
+        {
+            Mood::Asleep => Animation::Sleeping,
+            Mood::Sick | Mood::Hungry | Mood::Sad => Animation::Sad,
+            _ => Animation::Idle,
+        }
+    }
+}
+
+//The name used for the species in file names
+pub fn kind_file_name(pet_kind: &PetKind) -> &str
+{
+    match pet_kind
+    {
+        PetKind::Axalotl => "axolotl",
+        PetKind::Cat => "cat",
+        PetKind::Dog => "dog",
+        PetKind::Monkey => "monkey",
+        PetKind::Parrot => "parrot",
+        PetKind::Rabbit => "rabbit",
+        PetKind::Squirrel => "squirrel",
+    }
+}
+
+//Frames separated by lines with three dashes, without the blank ones
+pub fn parse_frames(text: &str) -> Vec<String>
+{
+    let mut frames = Vec::new();
+    let mut frame = Vec::new();
+
+    for line in text.lines().chain(std::iter::once("---"))
+    {
+        if line.trim() == "---"
+        {
+            if frame.iter().any(|line: &&str| !line.trim().is_empty())
+            {
+                frames.push(frame.join("\n"));
+            }
+            frame.clear();
+        }
+        else
+        {
+            frame.push(line.trim_end());
+        }
+    }
+
+    frames
+}
+
+//Reads the sections of an animation file. Lines starting with # before the first section
+//are comments, [name] starts an animation and --- separates its frames.
+pub fn parse_animations(text: &str) -> Result<HashMap<Animation, Vec<String>>, String>
+{
+    let mut sections: Vec<(Animation, Vec<&str>)> = Vec::new();
+
+    for line in text.lines()
+    {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']')
+        {
+            let name = &trimmed[1..trimmed.len() - 1];
+            let animation = Animation::from_str(name).ok_or(format!("Unknown animation [{}]", name))?;
+
+            if sections.iter().any(|(other, _)| *other == animation)
+            {
+                return Err(format!("Animation [{}] is defined twice", name));
+            }
+            sections.push((animation, Vec::new()));
+        }
+        else if let Some((_, lines)) = sections.last_mut()
+        {
+            lines.push(line);
+        }
+        else if !trimmed.is_empty() && !trimmed.starts_with('#')
+        {
+            return Err(format!("Art outside of an animation: '{}'", line));
+        }
+    }
+
+    let animations: HashMap<Animation, Vec<String>> = sections
+        .into_iter()
+        .map(|(animation, lines)| (animation, parse_frames(&lines.join("\n"))))
+        .filter(|(_, frames)| !frames.is_empty())
+        .collect();
+
+    for animation in Animation::ALL
+    {
+        if !animations.contains_key(&animation)
+        {
+            return Err(format!("Animation [{}] is missing", animation.name()));
+        }
+    }
+
+    Ok(animations)
+}
+
+type Frames = HashMap<PetKind, HashMap<Animation, Arc<[String]>>>;
+
+fn all_frames() -> &'static Frames
+{
+    static FRAMES: OnceLock<Frames> = OnceLock::new();
+
+    FRAMES.get_or_init(|| {
+        ANIMATION_FILES
+            .iter()
+            .map(|(kind, text)| {
+                let animations = parse_animations(text).unwrap_or_else(|e| panic!("data/animations for {} is not valid: {}", kind.to_string(), e));
+                (kind.clone(), animations.into_iter().map(|(animation, frames)| (animation, Arc::from(frames))).collect())
+            })
+            .collect()
+    })
+}
+
+pub fn frames(pet_kind: &PetKind, animation: Animation) -> &'static [String]
+{
+    &all_frames()[pet_kind][&animation]
+}
+
+//The same frames, shared instead of borrowed so they can stand in for a skin's
+pub fn shared_frames(pet_kind: &PetKind, animation: Animation) -> Arc<[String]>
+{
+    Arc::clone(&all_frames()[pet_kind][&animation])
+}
+
+//The frame to show after some time has passed, looping forever
+pub fn frame_at(frames: &[String], elapsed: Duration, fps: u32) -> &str
+{
+    if fps == 0
+    {
+        return &frames[0];
+    }
+
+    let index = (elapsed.as_millis() * fps as u128 / 1000) as usize;
+    &frames[index % frames.len()]
+}
+
+//Plays the frames in place in the terminal. Without a terminal, or with a frame rate of 0,
+//only the first frame is printed.
+pub fn play(frames: &[String], fps: u32, length: Duration)
+{
+    let mut out = io::stdout();
+
+    if fps == 0 || frames.len() < 2 || !out.is_terminal()
+    {
+        println!("{}", frames[0]);
+        return;
+    }
+
+    let height = frames.iter().map(|frame| frame.lines().count()).max().unwrap_or(1);
+    let delay = Duration::from_millis(1000 / fps as u64);
+    let count = ((length.as_millis() / delay.as_millis().max(1)) as usize).max(frames.len());
+
+    for index in 0..count
+    {
+        if index > 0
+        {
+            let _ = queue!(out, cursor::MoveUp(height as u16));
+        }
+
+        let mut lines: Vec<&str> = frames[index % frames.len()].lines().collect();
+        lines.resize(height, "");
+
+        for line in lines
+        {
+            let _ = queue!(out, terminal::Clear(ClearType::CurrentLine), Print(line), Print("\n"));
+        }
+        let _ = out.flush();
+
+        if index + 1 < count
+        {
+            thread::sleep(delay);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::modules::pet::Pet;
+use crate::modules::food::{get_food_for_pet, FoodGroup};
+use crate::modules::games::get_games;
+use crate::modules::clock::{Home, Phase};
+use crate::modules::simulation::{Action, Simulation};
+
+//Autopilot caretakers. A policy looks at the pet and picks the next action, which is then
+//applied like any other action - in the game on every tick or in a balance simulation.
+
+pub trait CarePolicy
+{
+    fn name(&self) -> &str;
+
+    fn next_action(&mut self, pet: &Pet, home: &Home, simulation: &Simulation) -> Action;
+}
+
+pub fn policy_by_name(name: &str) -> Option<Box<dyn CarePolicy + Send>>
+{
+    match name.to_lowercase().as_str()
+    {
+        "greedy" => Some(Box::new(GreedyPolicy)),
+        "neglectful" => Some(Box::new(NeglectfulPolicy::new(10))),
+        _ => None,
+    }
+}
+
+//Fixes whatever is most urgent right now: hunger first, then tiredness, then boredom
+pub struct GreedyPolicy;
+
+impl GreedyPolicy
+{
+    const HUNGRY: u8 = 7;
+    const BORED: u8 = 8;
+
+    //The most filling healthy food that doesn't overfeed the pet
+    fn best_food(pet: &Pet, simulation: &Simulation) -> Option<String>
+    {
+        get_food_for_pet(pet.kind())
+            .into_iter()
+            .filter_map(|name| simulation.food_map.get(&name).map(|food| (name, food)))
+            .filter(|(_, food)| pet.satiation() + food.satiation_gain <= pet.rules().full_stats)
+            .max_by_key(|(_, food)| (food.group != FoodGroup::Treat, food.satiation_gain))
+            .map(|(name, _)| name)
+    }
+
+    //The most fun game the pet can afford without getting tired
+    fn best_game(pet: &Pet, home: &Home, simulation: &Simulation) -> Option<String>
+    {
+        get_games(pet.kind())
+            .into_iter()
+            .filter_map(|name| simulation.games_map.get(&name).map(|game| (name, game)))
+            .filter(|(_, game)| !(game.daytime_only && home.clock.phase() == Phase::Night))
+            .filter(|(_, game)| pet.energy() > game.energy_cost + pet.rules().tired_energy && pet.satiation() >= game.satiation_cost)
+            .max_by_key(|(_, game)| game.happiness_increase)
+            .map(|(name, _)| name)
+    }
+}
+
+impl CarePolicy for GreedyPolicy
+{
+    fn name(&self) -> &str
+    {
+        "greedy"
+    }
+
+    fn next_action(&mut self, pet: &Pet, home: &Home, simulation: &Simulation) -> Action
+    {
+        if pet.is_asleep() || pet.is_egg()
+        {
+            return Action::Wait;
+        }
+
+        if pet.satiation() < Self::HUNGRY
+        {
+            if let Some(food) = Self::best_food(pet, simulation)
+            {
+                return Action::Feed(food);
+            }
+        }
+
+        if pet.is_tired()
+        {
+            return Action::Sleep;
+        }
+
+        if pet.happiness() < Self::BORED || pet.satiation() > pet.rules().full_stats
+        {
+            if let Some(game) = Self::best_game(pet, home, simulation)
+            {
+                return Action::Play(game);
+            }
+        }
+
+        Action::Wait
+    }
+}
+
+//Only remembers the pet every few ticks and then gives it a treat
+pub struct NeglectfulPolicy
+{
+    every: u32,
+    ticks: u32,
+}
+
+impl NeglectfulPolicy
+{
+    pub fn new(every: u32) -> Self
+    {
+        Self { every, ticks: 0 }
+    }
+}
+
+impl CarePolicy for NeglectfulPolicy
+{
+    fn name(&self) -> &str
+    {
+        "neglectful"
+    }
+
+    fn next_action(&mut self, pet: &Pet, _home: &Home, simulation: &Simulation) -> Action
+    {
+        self.ticks += 1;
+
+        if !self.ticks.is_multiple_of(self.every)
+        {
+            return Action::Wait;
+        }
+
+        get_food_for_pet(pet.kind())
+            .into_iter()
+            .find(|name| simulation.food_map.get(name).is_some_and(|food| food.group == FoodGroup::Treat))
+            .map(Action::Feed)
+            .unwrap_or(Action::Wait)
+    }
+}
@@ -0,0 +1,356 @@
+use crate::modules::pet::{is_dead, pets_list, Pet, PetKind};
+use crate::modules::food::{get_food_for_pet, initialize_food};
+use crate::modules::games::{get_games, initialize_games};
+use crate::modules::clock::Home;
+use crate::modules::journal::{EntryKind, Journal, JOURNAL_DIRECTORY};
+use crate::modules::status::{status_json, ActionResult};
+use crate::modules::rules::Difficulty;
+use crate::modules::locale::{food_name, game_name, kind_name};
+use crate::modules::repl::fuzzy_find;
+use crate::t;
+
+//Subcommands for driving pets from scripts and cron jobs, e.g. `tamagotchi feed Rex Bone`.
+//Each one loads the pet, applies the action, saves it and returns an exit code.
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_REFUSED: i32 = 1; //The action could not be done, e.g. unknown food
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_NO_PET: i32 = 3; //The pet does not exist or could not be loaded
+pub const EXIT_SAVE_FAILED: i32 = 4;
+pub const EXIT_DIED: i32 = 5; //The action was done but the pet lost its last life
+
+pub fn usage() -> String
+{
+    let commands = [
+        ("tamagotchi", t!("cli.usage_game")),
+        ("tamagotchi new <name> --kind <kind>", t!("cli.usage_new")),
+        ("    [--difficulty <difficulty>]", t!("cli.usage_difficulty")),
+        ("tamagotchi feed <name> <food>", t!("cli.usage_feed")),
+        ("tamagotchi play <name> <game>", t!("cli.usage_play")),
+        ("tamagotchi sleep <name>", t!("cli.usage_sleep")),
+        ("tamagotchi status <name>", t!("cli.usage_status")),
+        ("tamagotchi list", t!("cli.usage_list")),
+    ];
+
+    let lines: Vec<String> = commands.iter().map(|(command, description)| format!("  {:<35} {}", command, description)).collect();
+    format!("{}\n{}\n\n{}", t!("cli.usage"), lines.join("\n"), t!("cli.usage_format"))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat
+{
+    Text,
+    Json,
+}
+
+//Reads the --format option and removes it from the arguments
+pub fn parse_format(args: &mut Vec<String>) -> Result<OutputFormat, String>
+{
+    let index = match args.iter().position(|arg| arg == "--format")
+    {
+        Some(index) => index,
+        None => return Ok(OutputFormat::Text),
+    };
+
+    let format = match args.get(index + 1).map(|value| value.as_str())
+    {
+        Some("text") => OutputFormat::Text,
+        Some("json") => OutputFormat::Json,
+        _ => return Err(t!("cli.format")),
+    };
+
+    args.drain(index..=index + 1);
+    Ok(format)
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Subcommand
+{
+    New { name: String, kind: PetKind, difficulty: Difficulty },
+    Feed { name: String, food: String },
+    Play { name: String, game: String },
+    Sleep { name: String },
+    Status { name: String },
+    List,
+}
+
+//None when there is no subcommand and the interactive game should start.
+//Options that are not part of a subcommand (like --seed) are expected to be removed already.
+pub fn parse_args(args: &[String]) -> Result<Option<Subcommand>, String>
+{
+    let (command, rest) = match args.split_first()
+    {
+        Some(split) => split,
+        None => return Ok(None),
+    };
+
+    let name = || rest.first().cloned().ok_or(t!("cli.needs_name", command = command));
+
+    //Food and game names can have spaces, so the rest of the arguments are joined
+    let argument = |missing: &str| match rest.get(1..)
+    {
+        Some(words) if !words.is_empty() => Ok(words.join(" ")),
+        _ => Err(t!(missing, command = command)),
+    };
+
+    let subcommand = match command.as_str()
+    {
+        "new" =>
+        {
+            let name = name()?;
+            if name.starts_with("--")
+            {
+                return Err(t!("cli.name_first"));
+            }
+
+            let option = |option: &str| rest.iter().position(|arg| arg == option).and_then(|index| rest.get(index + 1));
+
+            let kind = match option("--kind")
+            {
+                Some(kind) => PetKind::from_str(kind).ok_or(t!("cli.unknown_kind", kind = kind))?,
+                None => return Err(t!("cli.needs_kind")),
+            };
+
+            let difficulty = match option("--difficulty")
+            {
+                Some(difficulty) => Difficulty::from_str(difficulty).ok_or(t!("cli.unknown_difficulty", difficulty = difficulty))?,
+                None => Difficulty::Classic,
+            };
+
+            Subcommand::New { name, kind, difficulty }
+        }
+        "feed" => Subcommand::Feed { name: name()?, food: argument("cli.needs_food")? },
+        "play" => Subcommand::Play { name: name()?, game: argument("cli.needs_game")? },
+        "sleep" => Subcommand::Sleep { name: name()? },
+        "status" => Subcommand::Status { name: name()? },
+        "list" => Subcommand::List,
+        _ => return Err(t!("cli.unknown_command", command = command)),
+    };
+
+    Ok(Some(subcommand))
+}
+
+pub fn run(subcommand: Subcommand, format: OutputFormat) -> i32
+{
+    let journal = Journal::new(JOURNAL_DIRECTORY);
+    let home = Home::load().unwrap_or_default();
+    let json = format == OutputFormat::Json;
+
+    let name = match &subcommand
+    {
+        Subcommand::New { name, kind, difficulty } =>
+        {
+            if pets_list().unwrap_or_default().contains(name)
+            {
+                eprintln!("{}", t!("cli.exists", name = name));
+                return EXIT_REFUSED;
+            }
+
+            let mut pet = Pet::new(name.clone(), kind.clone());
+            pet.set_difficulty(*difficulty);
+            pet.set_quiet(json);
+            journal.write(&pet, EntryKind::Created, t!("entry.created", name = pet.name(), kind = kind_name(pet.kind())));
+
+            let code = save(&pet, &journal);
+            if json
+            {
+                println!("{}", status_json(&pet, &home, Some(ActionResult { action: "new".to_string(), target: None, success: code == EXIT_OK })));
+            }
+            return code;
+        }
+        Subcommand::List => return list(format),
+        Subcommand::Feed { name, .. } | Subcommand::Play { name, .. } | Subcommand::Sleep { name } | Subcommand::Status { name } => name,
+    };
+
+    let name = match find(name, &pets_list().unwrap_or_default(), |name| name.to_string())
+    {
+        Some(name) => name,
+        None =>
+        {
+            eprintln!("{}", t!("cli.no_pet", name = name));
+            return EXIT_NO_PET;
+        }
+    };
+
+    let mut pet = match Pet::load_from_name(&name)
+    {
+        Ok(pet) => pet,
+        Err(e) =>
+        {
+            eprintln!("{}", t!("cli.load_failed", name = name, error = e));
+            return EXIT_NO_PET;
+        }
+    };
+    pet.set_quiet(json);
+    journal.write(&pet, EntryKind::Load, t!("entry.loaded"));
+
+    let (action, target) = match &subcommand
+    {
+        Subcommand::Feed { food, .. } => ("feed", Some(food.clone())),
+        Subcommand::Play { game, .. } => ("play", Some(game.clone())),
+        Subcommand::Sleep { .. } => ("sleep", None),
+        _ =>
+        {
+            if json
+            {
+                println!("{}", status_json(&pet, &home, None));
+            }
+            else
+            {
+                pet.display_stats(&home);
+            }
+            return EXIT_OK;
+        }
+    };
+
+    let code = act(&mut pet, action, target.as_deref(), &home, &journal);
+
+    if json
+    {
+        println!("{}", status_json(&pet, &home, Some(ActionResult { action: action.to_string(), target, success: code == EXIT_OK || code == EXIT_DIED })));
+    }
+
+    code
+}
+
+//Applies one action to a loaded pet and saves it
+pub fn act(pet: &mut Pet, action: &str, target: Option<&str>, home: &Home, journal: &Journal) -> i32
+{
+    if pet.is_egg()
+    {
+        eprintln!("{}", t!("game.still_egg", name = pet.name()));
+        return EXIT_REFUSED;
+    }
+
+    let target = target.unwrap_or_default();
+
+    let (done, kind, description) = match action
+    {
+        "feed" =>
+        {
+            let food = match find(target, &get_food_for_pet(pet.kind()), food_name)
+            {
+                Some(food) => food,
+                None =>
+                {
+                    eprintln!("{}", t!("cli.not_eaten", name = pet.name(), food = target));
+                    return EXIT_REFUSED;
+                }
+            };
+
+            pet.wake_early();
+            (pet.feed(&food, &initialize_food()), EntryKind::Feed, t!("command.feed", name = food_name(&food)))
+        }
+        "play" =>
+        {
+            let game = match find(target, &get_games(pet.kind()), game_name)
+            {
+                Some(game) => game,
+                None =>
+                {
+                    eprintln!("{}", t!("cli.not_played", name = pet.name(), game = target));
+                    return EXIT_REFUSED;
+                }
+            };
+
+            pet.wake_early();
+            (pet.play(&game, &initialize_games(), home), EntryKind::Play, t!("command.play", name = game_name(&game)))
+        }
+        _ =>
+        {
+            let tired = !pet.is_asleep() && pet.is_tired();
+            if tired
+            {
+                pet.sleep();
+            }
+            else
+            {
+                eprintln!("{}", t!("cli.not_tired", name = pet.name()));
+            }
+            (tired, EntryKind::Sleep, t!("command.sleep", name = pet.name()))
+        }
+    };
+
+    if !done
+    {
+        return EXIT_REFUSED;
+    }
+
+    journal.write(pet, kind, description);
+    journal.check(pet);
+
+    if pet.is_health_zero()
+    {
+        journal.write(pet, EntryKind::LifeLost, t!("entry.lost_life", lives = pet.lives()));
+    }
+
+    if is_dead(pet)
+    {
+        journal.write_death(pet);
+        return EXIT_DIED;
+    }
+
+    save(pet, journal)
+}
+
+//The name that fits what was typed, matched like in the interactive game. When several
+//names fit equally well they are suggested and nothing is picked.
+fn find(input: &str, names: &[String], translated: impl Fn(&str) -> String) -> Option<String>
+{
+    match fuzzy_find(input, names, &translated)
+    {
+        Ok(name) => Some(name),
+        Err(suggestions) =>
+        {
+            if !suggestions.is_empty()
+            {
+                let suggestions: Vec<String> = suggestions.iter().map(|name| translated(name)).collect();
+                eprintln!("{}", t!("repl.did_you_mean", names = suggestions.join(", ")));
+            }
+            None
+        }
+    }
+}
+
+fn save(pet: &Pet, journal: &Journal) -> i32
+{
+    match pet.save_pet_to_file()
+    {
+        Ok(()) =>
+        {
+            journal.write(pet, EntryKind::Save, t!("entry.saved"));
+            EXIT_OK
+        }
+        Err(e) =>
+        {
+            eprintln!("{}", t!("cli.save_failed", name = pet.name(), error = e));
+            EXIT_SAVE_FAILED
+        }
+    }
+}
+
+fn list(format: OutputFormat) -> i32
+{
+    let names = match pets_list()
+    {
+        Ok(names) => names,
+        Err(e) =>
+        {
+            eprintln!("{}", t!("cli.list_failed", error = e));
+            return EXIT_NO_PET;
+        }
+    };
+
+    print!("{}", format_list(&names, format));
+    EXIT_OK
+}
+
+//One name per line, or a JSON array of them
+pub fn format_list(names: &[String], format: OutputFormat) -> String
+{
+    match format
+    {
+        OutputFormat::Json => format!("{}\n", serde_json::to_string(names).expect("pet names are always valid JSON")),
+        OutputFormat::Text => names.iter().map(|name| format!("{}\n", name)).collect(),
+    }
+}
@@ -0,0 +1,181 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use crate::t;
+
+//The in-game clock is accelerated - every tick moves it forward by half an hour, so with
+//30 second ticks a full day passes in 24 real minutes. There is one clock for every pet,
+//kept by the session together with the lights in world/home.json.
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Phase
+{
+    Day,
+    Night,
+}
+
+impl Phase
+{
+    pub fn to_string(&self) -> &str
+    {
+        match self
+        {
+            Phase::Day => "Day",
+            Phase::Night => "Night",
+        }
+    }
+
+    //The name in the chosen language, to_string is kept for the JSON status
+    pub fn name(&self) -> String
+    {
+        t!(match self
+        {
+            Phase::Day => "phase.day",
+            Phase::Night => "phase.night",
+        })
+    }
+
+    pub fn icon(&self) -> &str
+    {
+        match self
+        {
+            Phase::Day => "☀",
+            Phase::Night => "🌙",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+
+pub struct GameClock
+{
+    minutes: u32, //In-game minutes since midnight of the first day
+}
+
+impl GameClock
+{
+    pub const MINUTES_PER_TICK: u32 = 30;
+    const DAY_STARTS: u8 = 7;
+    const NIGHT_STARTS: u8 = 21;
+
+    pub fn at(day: u32, hour: u8, minute: u8) -> Self
+    {
+        Self
+        {
+            minutes: day * 24 * 60 + hour as u32 * 60 + minute as u32,
+        }
+    }
+
+    pub fn day(&self) -> u32
+    {
+        self.minutes / (24 * 60)
+    }
+
+    pub fn hour(&self) -> u8
+    {
+        (self.minutes / 60 % 24) as u8
+    }
+
+    pub fn minute(&self) -> u8
+    {
+        (self.minutes % 60) as u8
+    }
+
+    pub fn phase(&self) -> Phase
+    {
+        if self.hour() >= Self::DAY_STARTS && self.hour() < Self::NIGHT_STARTS
+        {
+            Phase::Day
+        }
+        else
+        {
+            Phase::Night
+        }
+    }
+
+    pub fn advance_minutes(&mut self, minutes: u32)
+    {
+        self.minutes += minutes;
+    }
+
+    pub fn advance_hours(&mut self, hours: u8)
+    {
+        self.advance_minutes(hours as u32 * 60);
+    }
+}
+
+impl fmt::Display for GameClock
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(
+            f,
+            "{}",
+            t!(
+                "clock.time",
+                day = self.day() + 1,
+                time = format!("{:02}:{:02}", self.hour(), self.minute()),
+                icon = self.phase().icon(),
+                phase = self.phase().name()
+            )
+        )
+    }
+}
+
+//New pets start their first day in the morning
+impl Default for GameClock
+{
+    fn default() -> Self
+    {
+        Self::at(0, 8, 0)
+    }
+}
+
+//The home all the pets of a session live in. They share the time of day and the lights.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+
+pub struct Home
+{
+    pub clock: GameClock,
+    pub lights_on: bool,
+}
+
+impl Default for Home
+{
+    fn default() -> Self
+    {
+        Self
+        {
+            clock: GameClock::default(),
+            lights_on: true,
+        }
+    }
+}
+
+impl Home
+{
+    const FILE: &'static str = "world/home.json";
+
+    //"on" or "off" in the chosen language
+    pub fn lights(&self) -> String
+    {
+        t!(if self.lights_on {"lights.on"} else {"lights.off"})
+    }
+
+    pub fn load() -> io::Result<Self>
+    {
+        match fs::read_to_string(Self::FILE)
+        {
+            Ok(data) => Ok(serde_json::from_str(&data)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()>
+    {
+        fs::create_dir_all("world")?;
+        fs::write(Self::FILE, serde_json::to_string_pretty(&self)?)
+    }
+}
@@ -0,0 +1,291 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::sync::OnceLock;
+use crossterm::style::{Color, Stylize};
+use crate::modules::rules::Rules;
+use crate::t;
+
+//How the stats are shown. Fancy uses emoji and Unicode bars, Plain sticks to ASCII and the
+//screen reader mode writes everything out as words and numbers. Colours are an extra on top
+//of the mode and turn a stat yellow or red when it gets low. The settings come from
+//settings.json, overridden by environment variables, and NO_COLOR always turns colour off.
+
+pub const SETTINGS_FILE: &str = "settings.json";
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DisplayMode
+{
+    #[default]
+    Fancy,
+    Plain,
+    ScreenReader,
+}
+
+impl DisplayMode
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        match s.to_lowercase().as_str()
+        {
+            "fancy" => Some(DisplayMode::Fancy),
+            "plain" => Some(DisplayMode::Plain),
+            "screen-reader" => Some(DisplayMode::ScreenReader),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme
+{
+    #[default]
+    Classic,
+    Colourblind, //Blue and orange instead of green and red
+    HighContrast,
+}
+
+impl Theme
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        match s.to_lowercase().as_str()
+        {
+            "classic" => Some(Theme::Classic),
+            "colourblind" | "colorblind" => Some(Theme::Colourblind),
+            "high-contrast" => Some(Theme::HighContrast),
+            _ => None,
+        }
+    }
+
+    fn colour(&self, level: Level) -> Color
+    {
+        match (self, level)
+        {
+            (Theme::Classic, Level::Good) => Color::Green,
+            (Theme::Classic, Level::Warning) => Color::Yellow,
+            (Theme::Classic, Level::Danger) => Color::Red,
+            (Theme::Colourblind, Level::Good) => Color::Blue,
+            (Theme::Colourblind, Level::Warning) => Color::Yellow,
+            (Theme::Colourblind, Level::Danger) => Color::Rgb { r: 230, g: 120, b: 0 },
+            (Theme::HighContrast, Level::Good) => Color::White,
+            (Theme::HighContrast, Level::Warning) => Color::Yellow,
+            (Theme::HighContrast, Level::Danger) => Color::Magenta,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplaySettings
+{
+    pub mode: DisplayMode,
+    pub theme: Theme,
+    pub colour: bool,
+}
+
+impl Default for DisplaySettings
+{
+    fn default() -> Self
+    {
+        Self { mode: DisplayMode::Fancy, theme: Theme::Classic, colour: true }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct SettingsFile
+{
+    display: Option<DisplayMode>,
+    theme: Option<Theme>,
+    colour: Option<bool>,
+}
+
+impl DisplaySettings
+{
+    //Combines the settings file with the environment, which wins
+    pub fn from_sources(file: Option<&str>, var: impl Fn(&str) -> Option<String>) -> Result<Self, String>
+    {
+        let file: SettingsFile = match file
+        {
+            Some(text) => serde_json::from_str(text).map_err(|e| format!("{} is not valid: {}", SETTINGS_FILE, e))?,
+            None => SettingsFile::default(),
+        };
+
+        let mut settings = Self::default();
+        settings.mode = file.display.unwrap_or(settings.mode);
+        settings.theme = file.theme.unwrap_or(settings.theme);
+        settings.colour = file.colour.unwrap_or(settings.colour);
+
+        if let Some(mode) = var("TAMAGOTCHI_DISPLAY")
+        {
+            settings.mode = DisplayMode::from_str(&mode).ok_or(format!("TAMAGOTCHI_DISPLAY must be fancy, plain or screen-reader, not '{}'", mode))?;
+        }
+
+        if let Some(theme) = var("TAMAGOTCHI_THEME")
+        {
+            settings.theme = Theme::from_str(&theme).ok_or(format!("TAMAGOTCHI_THEME must be classic, colourblind or high-contrast, not '{}'", theme))?;
+        }
+
+        //https://no-color.org - any value that isn't empty turns colour off
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) || settings.mode == DisplayMode::ScreenReader
+        {
+            settings.colour = false;
+        }
+
+        Ok(settings)
+    }
+
+    //Problems with the settings are reported and the defaults are used instead
+    pub fn load() -> Self
+    {
+        let file = fs::read_to_string(SETTINGS_FILE).ok();
+
+        match Self::from_sources(file.as_deref(), |name| env::var(name).ok())
+        {
+            //No colour codes in files and pipes
+            Ok(settings) => Self { colour: settings.colour && io::stdout().is_terminal(), ..settings },
+            Err(e) =>
+            {
+                eprintln!("{}", t!("display.settings_invalid", error = e));
+                Self::default()
+            }
+        }
+    }
+}
+
+//Read once, the first time anything is displayed
+pub fn settings() -> DisplaySettings
+{
+    static SETTINGS: OnceLock<DisplaySettings> = OnceLock::new();
+    *SETTINGS.get_or_init(DisplaySettings::load)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level
+{
+    Good,
+    Warning,
+    Danger,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stat
+{
+    Lives,
+    Health,
+    Happiness,
+    Energy,
+    Satiation,
+}
+
+impl Stat
+{
+    //The name in the chosen language
+    pub fn name(&self) -> String
+    {
+        t!(match self
+        {
+            Stat::Lives => "stat.lives",
+            Stat::Health => "stat.health",
+            Stat::Happiness => "stat.happiness",
+            Stat::Energy => "stat.energy",
+            Stat::Satiation => "stat.satiation",
+        })
+    }
+
+    //The same thresholds as the health checks in the pet's rules: a tired pet shows as a
+    //warning, and so does an overfed one until it overeats enough to get sick
+    pub fn level(&self, value: u8, rules: &Rules) -> Level
+    {
+        match self
+        {
+            Stat::Lives => Level::Good,
+            Stat::Satiation if value == 0 || value > rules.satiation_dangerous => Level::Danger,
+            Stat::Satiation if value <= rules.low_stat() || value >= rules.satiation_high => Level::Warning,
+            Stat::Satiation => Level::Good,
+            _ if value <= rules.very_low_stat() => Level::Danger,
+            _ if value <= rules.tired_energy => Level::Warning,
+            _ => Level::Good,
+        }
+    }
+
+    //What a stat is shown out of: its cap, and for satiation the most a pet can eat before
+    //it starts getting sick
+    pub fn scale(&self, rules: &Rules) -> u8
+    {
+        match self
+        {
+            Stat::Lives => rules.lives,
+            Stat::Health => rules.max_health,
+            Stat::Happiness => rules.max_happiness,
+            Stat::Energy => rules.max_energy,
+            Stat::Satiation => rules.satiation_dangerous,
+        }
+    }
+
+    fn fancy_symbol(&self) -> &str
+    {
+        match self
+        {
+            Stat::Lives => "♥ ",
+            Stat::Health => "█ ",
+            Stat::Happiness => "😊",
+            Stat::Energy => "⚡",
+            Stat::Satiation => "🍎",
+        }
+    }
+}
+
+//One line for a stat in the chosen mode, e.g. "Health: [#######---] 7/10"
+pub fn stat_line(stat: Stat, value: u8, rules: &Rules, settings: &DisplaySettings) -> String
+{
+    let level = stat.level(value, rules);
+
+    let line = match settings.mode
+    {
+        DisplayMode::Fancy => format!("{}: {}", stat.name(), stat.fancy_symbol().repeat(value as usize)),
+        DisplayMode::Plain if stat == Stat::Lives => format!("{}: {}", stat.name(), value),
+        DisplayMode::Plain =>
+        {
+            let scale = stat.scale(rules);
+            let filled = value.min(scale) as usize;
+            format!("{:<10} [{}{}]{} {}/{}", format!("{}:", stat.name()), "#".repeat(filled), "-".repeat(scale as usize - filled), "+".repeat(value.saturating_sub(scale) as usize), value, scale)
+        }
+        DisplayMode::ScreenReader if stat == Stat::Lives => format!("{} {}", stat.name(), value),
+        DisplayMode::ScreenReader =>
+        {
+            let note = match (stat, level)
+            {
+                (Stat::Satiation, Level::Warning) if value >= rules.satiation_high => Some("display.overfed"),
+                (Stat::Satiation, Level::Danger) if value > rules.satiation_dangerous => Some("display.dangerously_overfed"),
+                (_, Level::Warning) => Some("display.low"),
+                (_, Level::Danger) => Some("display.very_low"),
+                _ => None,
+            };
+
+            match note
+            {
+                Some(note) => t!("display.out_of_note", stat = stat.name(), value = value, scale = stat.scale(rules), note = t!(note)),
+                None => t!("display.out_of", stat = stat.name(), value = value, scale = stat.scale(rules)),
+            }
+        }
+    };
+
+    paint(line, level, settings)
+}
+
+pub fn paint(text: String, level: Level, settings: &DisplaySettings) -> String
+{
+    if settings.colour
+    {
+        text.with(settings.theme.colour(level)).to_string()
+    }
+    else
+    {
+        text
+    }
+}
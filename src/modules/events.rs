@@ -0,0 +1,139 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::modules::pet::{Pet, PetKind};
+use crate::modules::rng::GameRng;
+use crate::modules::locale::translate_text;
+use crate::t;
+
+//Random world events are defined in data/events.json. On every tick there is a small
+//chance that something happens to a pet. Which event it is depends on the weights, which
+//can be different for every kind of pet, and some kinds react differently to an event.
+//The name of an event is its ID, the catalogs in data/locales translate it and its message.
+
+const EVENTS_DATA: &str = include_str!("../../data/events.json");
+
+#[derive(Debug, Deserialize, Clone, Default)]
+
+pub struct StatChanges
+{
+    #[serde(default)]
+    pub health: i8,
+    #[serde(default)]
+    pub happiness: i8,
+    #[serde(default)]
+    pub energy: i8,
+    #[serde(default)]
+    pub satiation: i8,
+}
+
+#[derive(Debug, Deserialize)]
+
+pub struct WorldEvent
+{
+    pub name: String,
+    pub message: String,
+    pub weight: u32,
+    #[serde(default)]
+    pub kind_weights: HashMap<PetKind, u32>,
+    pub effect: StatChanges,
+    #[serde(default)]
+    pub kind_effects: HashMap<PetKind, StatChanges>,
+}
+
+impl WorldEvent
+{
+    pub fn weight_for(&self, pet_kind: &PetKind) -> u32
+    {
+        self.kind_weights.get(pet_kind).copied().unwrap_or(self.weight)
+    }
+
+    pub fn effect_for(&self, pet_kind: &PetKind) -> &StatChanges
+    {
+        self.kind_effects.get(pet_kind).unwrap_or(&self.effect)
+    }
+
+    pub fn shown_name(&self) -> String
+    {
+        translate_text("event", &self.name, &self.name)
+    }
+
+    pub fn shown_message(&self) -> String
+    {
+        translate_text("event_message", &self.name, &self.message)
+    }
+
+    //Applies the event to the pet and returns the line for the session log
+    pub fn apply(&self, pet: &mut Pet) -> String
+    {
+        let effect = self.effect_for(pet.kind());
+
+        pet.set_health(effect.health);
+        pet.set_happiness(effect.happiness);
+        pet.set_energy(effect.energy);
+        pet.set_satiation(effect.satiation);
+
+        let mut changes = Vec::new();
+        for (stat, value) in [("stat.health", effect.health), ("stat.happiness", effect.happiness), ("stat.energy", effect.energy), ("stat.satiation", effect.satiation)]
+        {
+            if value != 0
+            {
+                changes.push(format!("{} {:+}", t!(stat), value));
+            }
+        }
+
+        t!("event.line", event = self.shown_name(), name = pet.name(), message = self.shown_message(), changes = changes.join(", "))
+    }
+}
+
+pub struct EventTable
+{
+    events: Vec<WorldEvent>,
+}
+
+impl EventTable
+{
+    const EVENT_CHANCE: u32 = 15; //Percent chance of an event on each tick
+
+    pub fn events(&self) -> &[WorldEvent]
+    {
+        &self.events
+    }
+
+    //Rolls for an event for this pet, None if nothing happens
+    pub fn roll(&self, pet_kind: &PetKind, rng: &mut GameRng) -> Option<&WorldEvent>
+    {
+        if !rng.chance(Self::EVENT_CHANCE)
+        {
+            return None;
+        }
+
+        self.pick(pet_kind, rng)
+    }
+
+    //Picks an event using the weights for this kind of pet
+    pub fn pick(&self, pet_kind: &PetKind, rng: &mut GameRng) -> Option<&WorldEvent>
+    {
+        let total: u32 = self.events.iter().map(|event| event.weight_for(pet_kind)).sum();
+        let mut roll = rng.below(total);
+
+        for event in &self.events
+        {
+            let weight = event.weight_for(pet_kind);
+
+            if roll < weight
+            {
+                return Some(event);
+            }
+
+            roll -= weight;
+        }
+
+        None
+    }
+}
+
+pub fn initialize_events() -> EventTable
+{
+    let events: Vec<WorldEvent> = serde_json::from_str(EVENTS_DATA).expect("data/events.json is not valid");
+    EventTable { events }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::modules::pet::PetKind;
 
@@ -33,37 +34,80 @@ const POPCORN: &str = "Popcorn";
 const NUTS: &str = "Nuts";
 const PISTACHIO: &str = "Pistachio";
 
-pub fn initialize_food() -> HashMap<String, (u8, u8)> 
+//Nutrition categories used for the pet's diet history
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum FoodGroup
+{
+    Protein,
+    FruitVeg,
+    Treat,
+}
+
+impl FoodGroup
+{
+    pub fn to_string(&self) -> &str
+    {
+        match self
+        {
+            FoodGroup::Protein => "Protein",
+            FoodGroup::FruitVeg => "Fruit/Veg",
+            FoodGroup::Treat => "Treat",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+
+pub struct FoodEffect
+{
+    pub satiation_gain: u8,
+    pub energy_gain: u8,
+    pub shelf_life: Option<u8>, //Hours before the food spoils when left out, None if it keeps
+    pub group: FoodGroup,
+}
+
+pub fn initialize_food() -> HashMap<String, FoodEffect> 
 {
     let mut food_map = HashMap::new();
 
+    use FoodGroup::*;
+
     let foods = vec![
-        (FISH, (3, 1)),   // (Satiation, Energy)
-        (OCTOPUS, (2, 1)),
-        (SHRIMP, (2, 1)),
-        (MILK, (1, 1)),
-        (STEAK, (3, 1)),
-        (CHICKEN, (3, 2)),
-        (WHISKAS, (4, 2)),
-        (SCOOBY_SNACKS, (1, 1)),
-        (BONE, (2, 1)),
-        (CARROT, (3, 2)),
-        (LETTUCE, (1, 1)),
-        (SPINACH, (2, 1)),
-        (SEEDS, (2, 1)),
-        (BERRIES, (1, 1)),
-        (RASPBERRIES, (1, 1)),
-        (BANANA, (4, 2)),
-        (CAKE, (3, 2)),
-        (BISCUITS, (2, 1)),
-        (POPCORN, (2, 1)),
-        (NUTS, (3, 2)),
-        (PISTACHIO, (2, 2)),
+        (FISH, (3, 1), Some(3), Protein),   // (Satiation, Energy), Shelf life in hours, Food group
+        (OCTOPUS, (2, 1), Some(3), Protein),
+        (SHRIMP, (2, 1), Some(2), Protein),
+        (MILK, (1, 1), Some(2), Protein),
+        (STEAK, (3, 1), Some(4), Protein),
+        (CHICKEN, (3, 2), Some(4), Protein),
+        (WHISKAS, (4, 2), Some(8), Protein),
+        (SCOOBY_SNACKS, (1, 1), None, Treat),
+        (BONE, (2, 1), None, Protein),
+        (CARROT, (3, 2), Some(12), FruitVeg),
+        (LETTUCE, (1, 1), Some(6), FruitVeg),
+        (SPINACH, (2, 1), Some(6), FruitVeg),
+        (SEEDS, (2, 1), None, Protein),
+        (BERRIES, (1, 1), Some(6), FruitVeg),
+        (RASPBERRIES, (1, 1), Some(4), FruitVeg),
+        (BANANA, (4, 2), Some(8), FruitVeg),
+        (CAKE, (3, 2), Some(12), Treat),
+        (BISCUITS, (2, 1), Some(48), Treat),
+        (POPCORN, (2, 1), Some(24), Treat),
+        (NUTS, (3, 2), None, Protein),
+        (PISTACHIO, (2, 2), None, Protein),
     ];
 
-    for (name, stats) in foods 
+    for (name, (satiation_gain, energy_gain), shelf_life, group) in foods 
     {
-        food_map.insert(name.to_string(), stats);
+        food_map.insert(
+            name.to_string(),
+            FoodEffect 
+            {
+                satiation_gain,
+                energy_gain,
+                shelf_life,
+                group,
+            },
+        );
     }
 
     food_map
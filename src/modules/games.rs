@@ -8,6 +8,8 @@ pub struct GameEffect
     pub happiness_increase: u8, 
     pub energy_cost: u8,       
     pub satiation_cost: u8,
+    pub daytime_only: bool, //Games that can't be played in the dark
+    pub multiplayer: bool,  //Games two pets can play together
 }
 
 //Games:
@@ -35,6 +37,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 4,
                 energy_cost: 4,
                 satiation_cost: 2,
+                daytime_only: false,
+                multiplayer: false,
             },
         ),
         (
@@ -44,6 +48,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 5,
                 energy_cost: 5,
                 satiation_cost: 3,
+                daytime_only: false,
+                multiplayer: true,
             },
         ),
         (
@@ -53,6 +59,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 6,
                 energy_cost: 6,
                 satiation_cost: 4,
+                daytime_only: true,
+                multiplayer: false,
             },
         ),
         (
@@ -62,6 +70,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 4,
                 energy_cost: 4,
                 satiation_cost: 2,
+                daytime_only: false,
+                multiplayer: true,
             },
         ),
         (
@@ -71,6 +81,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 5,
                 energy_cost: 5,
                 satiation_cost: 3,
+                daytime_only: false,
+                multiplayer: false,
             },
         ),
         (
@@ -80,6 +92,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 6,
                 energy_cost: 5,
                 satiation_cost: 3,
+                daytime_only: true,
+                multiplayer: true,
             },
         ),
         (
@@ -89,6 +103,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 4,
                 energy_cost: 4,
                 satiation_cost: 2,
+                daytime_only: false,
+                multiplayer: true,
             },
         ),
         (
@@ -98,6 +114,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 3,
                 energy_cost: 2,
                 satiation_cost: 1,
+                daytime_only: false,
+                multiplayer: false,
             },
         ),
         (
@@ -107,6 +125,8 @@ pub fn initialize_games() -> HashMap<String, GameEffect>
                 happiness_increase: 5,
                 energy_cost: 4,
                 satiation_cost: 2,
+                daytime_only: false,
+                multiplayer: false,
             },
         ),
     ];
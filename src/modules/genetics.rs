@@ -0,0 +1,259 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use crate::modules::pet::{Pet, PetKind};
+use crate::modules::rng::GameRng;
+use crate::modules::locale::kind_name;
+use crate::t;
+
+//Simple genetic model for breeding. Every gene is taken from one of the two parents at
+//random, with a small chance of a mutation. Breeding with the same seed always gives the
+//same offspring. Pets that were not bred start with the traits of their kind.
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum Trait
+{
+    Playful,  //Every game is worth 1 more happiness
+    Lazy,     //Every tick asleep restores 1 more energy
+    Greedy,   //Every meal it is given is worth 1 more satiation
+    Cheerful, //The lights at night don't make it unhappy
+    Grumpy,   //Waking it up early costs 1 more happiness
+    Hardy,    //Staying up at night doesn't tire it
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub enum Colour
+{
+    #[default]
+    Classic,
+    Golden,
+    Spotted,
+    Albino,
+    Midnight,
+}
+
+const TRAITS: [Trait; 6] = [Trait::Playful, Trait::Lazy, Trait::Greedy, Trait::Cheerful, Trait::Grumpy, Trait::Hardy];
+const COLOURS: [Colour; 5] = [Colour::Classic, Colour::Golden, Colour::Spotted, Colour::Albino, Colour::Midnight];
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+
+pub struct Genes
+{
+    pub energy_modifier: i8,    //Added to the starting energy of the offspring
+    pub happiness_modifier: i8, //Added to the starting happiness of the offspring
+    pub traits: Vec<Trait>,
+    pub colour: Colour,
+}
+
+impl Genes
+{
+    const MUTATION_CHANCE: u32 = 10;
+    const MAX_MODIFIER: i8 = 3;
+    const MAX_TRAITS: usize = 2;
+
+    //The genes of a pet that was not bred, the trait its kind is known for
+    pub fn founder(kind: &PetKind) -> Genes
+    {
+        let personality = match kind
+        {
+            PetKind::Axalotl => Trait::Grumpy,
+            PetKind::Cat => Trait::Lazy,
+            PetKind::Dog => Trait::Cheerful,
+            PetKind::Monkey => Trait::Playful,
+            PetKind::Parrot => Trait::Playful,
+            PetKind::Rabbit => Trait::Hardy,
+            PetKind::Squirrel => Trait::Greedy,
+        };
+
+        Genes
+        {
+            traits: vec![personality],
+            ..Genes::default()
+        }
+    }
+
+    pub fn has(&self, personality: Trait) -> bool
+    {
+        self.traits.contains(&personality)
+    }
+
+    pub fn inherit(a: &Genes, b: &Genes, rng: &mut GameRng) -> Genes
+    {
+        let inherit_modifier = |x: i8, y: i8, rng: &mut GameRng| -> i8
+        {
+            let mut value = if rng.chance(50) {x} else {y};
+
+            if rng.chance(Self::MUTATION_CHANCE)
+            {
+                value += if rng.chance(50) {1} else {-1};
+            }
+
+            value.clamp(-Self::MAX_MODIFIER, Self::MAX_MODIFIER)
+        };
+
+        let energy_modifier = inherit_modifier(a.energy_modifier, b.energy_modifier, rng);
+        let happiness_modifier = inherit_modifier(a.happiness_modifier, b.happiness_modifier, rng);
+
+        let mut traits = Vec::new();
+        for personality in a.traits.iter().chain(b.traits.iter())
+        {
+            if rng.chance(50) && !traits.contains(personality) && traits.len() < Self::MAX_TRAITS
+            {
+                traits.push(*personality);
+            }
+        }
+
+        if rng.chance(Self::MUTATION_CHANCE) && traits.len() < Self::MAX_TRAITS
+        {
+            let personality = *rng.pick(&TRAITS);
+            if !traits.contains(&personality)
+            {
+                traits.push(personality);
+            }
+        }
+
+        let mut colour = if rng.chance(50) {a.colour} else {b.colour};
+        if rng.chance(Self::MUTATION_CHANCE)
+        {
+            colour = *rng.pick(&COLOURS);
+        }
+
+        Genes
+        {
+            energy_modifier,
+            happiness_modifier,
+            traits,
+            colour,
+        }
+    }
+}
+
+pub fn breed(a: &Pet, b: &Pet, name: String, seed: u64) -> Result<Pet, String>
+{
+    if a.name() == b.name()
+    {
+        return Err(t!("breed.same_pet"));
+    }
+
+    if a.kind() != b.kind()
+    {
+        return Err(t!("breed.different_kinds", name = a.name(), other = b.name()));
+    }
+
+    if !a.is_adult() || !b.is_adult()
+    {
+        return Err(t!("breed.not_adults", name = a.name(), other = b.name()));
+    }
+
+    let mut rng = GameRng::new(seed);
+    let genes = Genes::inherit(a.genes(), b.genes(), &mut rng);
+
+    //The egg is played at the same difficulty as its first parent
+    let mut egg = Pet::from_egg(name, a.kind().clone(), genes);
+    egg.set_difficulty(a.difficulty());
+    Ok(egg)
+}
+
+//Family tree of every pet born in the game, saved in world/family_tree.json
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+
+pub struct Lineage
+{
+    pub kind: PetKind,
+    pub parents: Option<(String, String)>,
+    pub generation: u32,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+
+pub struct FamilyTree
+{
+    pets: HashMap<String, Lineage>,
+}
+
+impl FamilyTree
+{
+    const FILE: &'static str = "world/family_tree.json";
+
+    //Pets that were not bred are added as founders of their own line
+    pub fn add_birth(&mut self, child: &Pet, a: &Pet, b: &Pet)
+    {
+        for parent in [a, b]
+        {
+            self.pets.entry(parent.name().to_string()).or_insert(Lineage
+            {
+                kind: parent.kind().clone(),
+                parents: None,
+                generation: 1,
+            });
+        }
+
+        let generation = self.pets[a.name()].generation.max(self.pets[b.name()].generation) + 1;
+
+        self.pets.insert(child.name().to_string(), Lineage
+        {
+            kind: child.kind().clone(),
+            parents: Some((a.name().to_string(), b.name().to_string())),
+            generation,
+        });
+    }
+
+    pub fn lineage(&self, name: &str) -> Option<&Lineage>
+    {
+        self.pets.get(name)
+    }
+
+    pub fn children(&self, name: &str) -> Vec<String>
+    {
+        let mut children: Vec<String> = self.pets
+            .iter()
+            .filter(|(_, lineage)| lineage.parents.as_ref().is_some_and(|(a, b)| a == name || b == name))
+            .map(|(child, _)| child.clone())
+            .collect();
+
+        children.sort();
+        children
+    }
+
+    //Prints the ancestors of a pet as an indented tree
+    pub fn display_ancestors(&self, name: &str)
+    {
+        self.display_ancestors_at(name, 0);
+    }
+
+    fn display_ancestors_at(&self, name: &str, depth: usize)
+    {
+        match self.pets.get(name)
+        {
+            Some(lineage) =>
+            {
+                println!("{}{}", "    ".repeat(depth), t!("tree.lineage", name = name, kind = kind_name(&lineage.kind), generation = lineage.generation));
+
+                if let Some((a, b)) = &lineage.parents
+                {
+                    self.display_ancestors_at(a, depth + 1);
+                    self.display_ancestors_at(b, depth + 1);
+                }
+            }
+            None => println!("{}{}", "    ".repeat(depth), name),
+        }
+    }
+
+    pub fn load() -> io::Result<Self>
+    {
+        match fs::read_to_string(Self::FILE)
+        {
+            Ok(data) => Ok(serde_json::from_str(&data)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()>
+    {
+        fs::create_dir_all("world")?;
+        fs::write(Self::FILE, serde_json::to_string_pretty(&self)?)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::modules::pet::Pet;
+use crate::modules::rng::GameRng;
+use crate::modules::clock::Home;
+use crate::modules::display::Stat;
+use crate::t;
+
+//Every action taken in the game menu is kept as a command with the state of the session
+//before and after it, so the last action can be undone. Undo is limited: only the last
+//action can be undone, only until the next tick, and never if a pet lost a life.
+
+//Everything an action can change: the pets and what the session keeps for all of them
+#[derive(Clone, PartialEq)]
+
+pub struct Snapshot
+{
+    pub pets: Vec<Pet>,
+    pub rng: GameRng,
+    pub home: Home,
+    pub log_length: usize,
+}
+
+pub struct Command
+{
+    pub description: String,
+    pub before: Snapshot,
+    pub after: Snapshot,
+    undoable: bool,
+}
+
+impl Command
+{
+    pub fn is_undoable(&self) -> bool
+    {
+        self.undoable
+    }
+
+    //What the command changed, e.g. "Rex: Satiation 7 -> 10"
+    pub fn changes(&self) -> Vec<String>
+    {
+        let mut changes = Vec::new();
+
+        for (before, after) in self.before.pets.iter().zip(self.after.pets.iter())
+        {
+            let stats = [
+                (Stat::Lives, before.lives(), after.lives()),
+                (Stat::Health, before.health(), after.health()),
+                (Stat::Happiness, before.happiness(), after.happiness()),
+                (Stat::Energy, before.energy(), after.energy()),
+                (Stat::Satiation, before.satiation(), after.satiation()),
+            ];
+
+            let changed: Vec<String> = stats
+                .iter()
+                .filter(|(_, old, new)| old != new)
+                .map(|(stat, old, new)| format!("{} {} -> {}", stat.name(), old, new))
+                .collect();
+
+            if !changed.is_empty()
+            {
+                changes.push(format!("{}: {}", after.name(), changed.join(", ")));
+            }
+        }
+
+        changes
+    }
+
+    fn lost_a_life(&self) -> bool
+    {
+        self.before.pets.iter().zip(self.after.pets.iter()).any(|(before, after)| after.lives() < before.lives())
+    }
+}
+
+#[derive(Default)]
+
+pub struct History
+{
+    commands: Vec<Command>,
+}
+
+impl History
+{
+    const MAX_COMMANDS: usize = 100;
+
+    pub fn commands(&self) -> &[Command]
+    {
+        &self.commands
+    }
+
+    pub fn record(&mut self, description: String, before: Snapshot, after: Snapshot)
+    {
+        for command in &mut self.commands
+        {
+            command.undoable = false;
+        }
+
+        self.commands.push(Command
+        {
+            description,
+            before,
+            after,
+            undoable: true,
+        });
+
+        if self.commands.len() > Self::MAX_COMMANDS
+        {
+            self.commands.remove(0);
+        }
+    }
+
+    //Time has passed, so going back would also undo the tick
+    pub fn invalidate(&mut self)
+    {
+        if let Some(last) = self.commands.last_mut()
+        {
+            last.undoable = false;
+        }
+    }
+
+    //Takes the last command back out of the history, the session is restored to its before
+    pub fn undo(&mut self, pets: &[Pet]) -> Result<Command, String>
+    {
+        let last = match self.commands.last()
+        {
+            Some(last) if last.undoable => last,
+            Some(_) => return Err(t!("undo.expired")),
+            None => return Err(t!("undo.nothing")),
+        };
+
+        if last.lost_a_life()
+        {
+            return Err(t!("undo.lost_life"));
+        }
+
+        let same_pets = pets.len() == last.after.pets.len()
+            && pets.iter().zip(last.after.pets.iter()).all(|(pet, after)| pet.name() == after.name());
+
+        if !same_pets
+        {
+            return Err(t!("undo.pets_changed"));
+        }
+
+        Ok(self.commands.pop().unwrap())
+    }
+}
@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::modules::pet::Pet;
+use crate::modules::display::Stat;
+use crate::modules::locale::translate_text;
+use crate::t;
+
+//Append-only journal of everything that happens to a pet, one JSON object per line in
+//journals/<name>.jsonl. Every entry keeps a snapshot of the pet's stats.
+
+pub const JOURNAL_DIRECTORY: &str = "journals";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum EntryKind
+{
+    Created,
+    Load,
+    Save,
+    Feed,
+    Play,
+    Sleep,
+    Leave,
+    Cook,
+    Lights,
+    Tick,
+    Event,
+    Warning,
+    LifeLost,
+    Death,
+    Undo,
+}
+
+impl EntryKind
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        const KINDS: [EntryKind; 15] = [
+            EntryKind::Created, EntryKind::Load, EntryKind::Save, EntryKind::Feed, EntryKind::Play,
+            EntryKind::Sleep, EntryKind::Leave, EntryKind::Cook, EntryKind::Lights, EntryKind::Tick,
+            EntryKind::Event, EntryKind::Warning, EntryKind::LifeLost, EntryKind::Death, EntryKind::Undo,
+        ];
+
+        KINDS.into_iter().find(|kind| format!("{:?}", kind).eq_ignore_ascii_case(s) || kind.name().eq_ignore_ascii_case(s))
+    }
+
+    //The name in the chosen language
+    pub fn name(&self) -> String
+    {
+        let id = format!("{:?}", self);
+        translate_text("entry_kind", &id, &id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+
+pub struct JournalEntry
+{
+    pub timestamp: u64, //Seconds since the Unix epoch
+    pub kind: EntryKind,
+    pub message: String,
+    pub lives: u8,
+    pub health: u8,
+    pub happiness: u8,
+    pub energy: u8,
+    pub satiation: u8,
+}
+
+impl JournalEntry
+{
+    pub fn new(kind: EntryKind, message: String, pet: &Pet) -> Self
+    {
+        Self
+        {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+            kind,
+            message,
+            lives: pet.lives(),
+            health: pet.health(),
+            happiness: pet.happiness(),
+            energy: pet.energy(),
+            satiation: pet.satiation(),
+        }
+    }
+
+    pub fn to_line(&self) -> String
+    {
+        let stats = [
+            (Stat::Lives, self.lives),
+            (Stat::Health, self.health),
+            (Stat::Happiness, self.happiness),
+            (Stat::Energy, self.energy),
+            (Stat::Satiation, self.satiation),
+        ];
+
+        format!(
+            "{} {:<8} {} ({})",
+            format_timestamp(self.timestamp),
+            self.kind.name(),
+            self.message,
+            stats.iter().map(|(stat, value)| format!("{} {}", stat.name(), value)).collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+//Journals can be switched off, e.g. for tests and simulations that shouldn't write files
+#[derive(Default)]
+
+pub struct Journal
+{
+    directory: Option<PathBuf>,
+}
+
+impl Journal
+{
+    pub fn new(directory: &str) -> Self
+    {
+        Self { directory: Some(PathBuf::from(directory)) }
+    }
+
+    pub fn disabled() -> Self
+    {
+        Self::default()
+    }
+
+    fn path(&self, name: &str) -> Option<PathBuf>
+    {
+        self.directory.as_ref().map(|directory| directory.join(format!("{}.jsonl", name)))
+    }
+
+    pub fn append(&self, name: &str, entry: &JournalEntry) -> io::Result<()>
+    {
+        let path = match self.path(name)
+        {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        if let Some(directory) = &self.directory
+        {
+            fs::create_dir_all(directory)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)
+    }
+
+    //Journal problems never interrupt the game, they are only reported
+    pub fn write(&self, pet: &Pet, kind: EntryKind, message: String)
+    {
+        if let Err(e) = self.append(pet.name(), &JournalEntry::new(kind, message, pet))
+        {
+            eprintln!("{}", t!("journal.write_failed", name = pet.name(), error = e));
+        }
+    }
+
+    //The journal of a dead pet is put aside with the time of death in its name, so that a
+    //new pet with the same name starts a journal of its own
+    pub fn write_death(&self, pet: &Pet)
+    {
+        let entry = JournalEntry::new(EntryKind::Death, t!("entry.died"), pet);
+        let retired = format!("{}-died-{}", pet.name(), entry.timestamp);
+
+        let result = self.append(pet.name(), &entry).and_then(|_| match (self.path(pet.name()), self.path(&retired))
+        {
+            (Some(from), Some(to)) => fs::rename(from, to),
+            _ => Ok(()),
+        });
+
+        if let Err(e) = result
+        {
+            eprintln!("{}", t!("journal.write_failed", name = pet.name(), error = e));
+        }
+    }
+
+    //Runs the health checks done after every action and writes down what they found
+    pub fn check(&self, pet: &mut Pet)
+    {
+        for warning in [pet.check_satiation(), pet.check_energy()].into_iter().flatten()
+        {
+            self.write(pet, EntryKind::Warning, warning.name());
+        }
+    }
+
+    pub fn read(&self, name: &str) -> io::Result<Vec<JournalEntry>>
+    {
+        let path = match self.path(name)
+        {
+            Some(path) => path,
+            None => return Ok(Vec::new()),
+        };
+
+        let data = match fs::read_to_string(path)
+        {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = Vec::new();
+        for line in data.lines().filter(|line| !line.trim().is_empty())
+        {
+            entries.push(serde_json::from_str(line)?);
+        }
+
+        Ok(entries)
+    }
+}
+
+//Newest entries first, optionally only one kind, split into pages
+pub fn filter_entries(entries: &[JournalEntry], kind: Option<EntryKind>) -> Vec<&JournalEntry>
+{
+    entries
+        .iter()
+        .rev()
+        .filter(|entry| kind.is_none_or(|kind| entry.kind == kind))
+        .collect()
+}
+
+pub fn page<T>(items: &[T], page: usize, per_page: usize) -> &[T]
+{
+    let start = (page * per_page).min(items.len());
+    let end = (start + per_page).min(items.len());
+    &items[start..end]
+}
+
+//Formats Unix time as "YYYY-MM-DD HH:MM:SS" in UTC
+pub fn format_timestamp(timestamp: u64) -> String
+{
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    //Converting days since 1970-01-01 to a civil date
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 {mp + 3} else {mp - 9};
+    let year = year_of_era + era * 400 + if month <= 2 {1} else {0};
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year, month, day, seconds / 3600, seconds / 60 % 60, seconds % 60
+    )
+}
@@ -0,0 +1,269 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::sync::OnceLock;
+use crate::modules::pet::PetKind;
+use crate::modules::display::SETTINGS_FILE;
+
+//Translations. Every message is looked up by a stable ID in data/locales/<code>.txt, one
+//"id = text" per line, with parameters named in braces, e.g. {name} was fed {food}.
+//Foods, games, recipes and species keep their English names in the code and in saved files and are
+//only translated when they are shown. Anything missing from a catalog falls back to English.
+
+const CATALOGS: [(Locale, &str); 3] = [
+    (Locale::English, include_str!("../../data/locales/en.txt")),
+    (Locale::Bulgarian, include_str!("../../data/locales/bg.txt")),
+    (Locale::German, include_str!("../../data/locales/de.txt")),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Locale
+{
+    #[default]
+    English,
+    Bulgarian,
+    German,
+}
+
+impl Locale
+{
+    pub const ALL: [Locale; 3] = [Locale::English, Locale::Bulgarian, Locale::German];
+
+    //Accepts codes like "de" as well as system locales like "de_DE.UTF-8"
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        let code = s.split(['_', '-', '.']).next().unwrap_or("").to_lowercase();
+        Self::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    pub fn code(&self) -> &str
+    {
+        match self
+        {
+            Locale::English => "en",
+            Locale::Bulgarian => "bg",
+            Locale::German => "de",
+        }
+    }
+
+    //Picks the language from TAMAGOTCHI_LANG, then settings.json, then the system locale
+    pub fn from_sources(file: Option<&str>, var: impl Fn(&str) -> Option<String>) -> Result<Self, String>
+    {
+        #[derive(Deserialize, Default)]
+        struct SettingsFile
+        {
+            language: Option<String>,
+        }
+
+        let file: SettingsFile = match file
+        {
+            Some(text) => serde_json::from_str(text).map_err(|e| format!("{} is not valid: {}", SETTINGS_FILE, e))?,
+            None => SettingsFile::default(),
+        };
+
+        let chosen = var("TAMAGOTCHI_LANG").map(|code| ("TAMAGOTCHI_LANG", code)).or(file.language.map(|code| ("language", code)));
+        if let Some((source, code)) = chosen
+        {
+            return Self::from_str(&code).ok_or(format!("{} must be en, bg or de, not '{}'", source, code));
+        }
+
+        //The system locale is only a hint, e.g. LANG=C means nothing in particular
+        Ok(["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| var(name))
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_str(&value))
+            .unwrap_or_default())
+    }
+
+    pub fn load() -> Self
+    {
+        let file = fs::read_to_string(SETTINGS_FILE).ok();
+
+        match Self::from_sources(file.as_deref(), |name| env::var(name).ok())
+        {
+            Ok(locale) => locale,
+            Err(e) =>
+            {
+                eprintln!("Warning: {}. Using English.", e);
+                Self::default()
+            }
+        }
+    }
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+//The language can be chosen once, before anything is shown. Returns false if it was too late.
+pub fn set_locale(locale: Locale) -> bool
+{
+    LOCALE.set(locale).is_ok() || self::locale() == locale
+}
+
+pub fn locale() -> Locale
+{
+    *LOCALE.get_or_init(Locale::load)
+}
+
+//Reads a catalog, reporting the first broken line
+pub fn parse_catalog(text: &str) -> Result<HashMap<String, String>, String>
+{
+    let mut messages = HashMap::new();
+
+    for (number, line) in text.lines().enumerate()
+    {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#')
+        {
+            continue;
+        }
+
+        let (id, message) = line.split_once('=').ok_or(format!("Line {} has no '=': '{}'", number + 1, line))?;
+        let id = id.trim();
+
+        if id.is_empty() || id.contains(char::is_whitespace)
+        {
+            return Err(format!("Line {} has an invalid ID: '{}'", number + 1, id));
+        }
+
+        if messages.insert(id.to_string(), message.trim().to_string()).is_some()
+        {
+            return Err(format!("{} is defined twice", id));
+        }
+    }
+
+    Ok(messages)
+}
+
+pub fn catalog(locale: Locale) -> &'static HashMap<String, String>
+{
+    static MESSAGES: OnceLock<HashMap<Locale, HashMap<String, String>>> = OnceLock::new();
+
+    let catalogs = MESSAGES.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(locale, text)| {
+                let messages = parse_catalog(text).unwrap_or_else(|e| panic!("data/locales/{}.txt is not valid: {}", locale.code(), e));
+                (*locale, messages)
+            })
+            .collect()
+    });
+
+    &catalogs[&locale]
+}
+
+fn lookup(locale: Locale, id: &str) -> Option<&'static str>
+{
+    catalog(locale).get(id).or(catalog(Locale::English).get(id)).map(|message| message.as_str())
+}
+
+//Puts the parameters into a message. Unknown parameters are left as they are.
+pub fn fill(message: &str, args: &[(&str, String)]) -> String
+{
+    let mut filled = String::new();
+    let mut rest = message;
+
+    while let Some(start) = rest.find('{')
+    {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| (value, end))
+        });
+
+        match value
+        {
+            Some((value, end)) =>
+            {
+                filled.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None =>
+            {
+                filled.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    filled.push_str(rest);
+    filled
+}
+
+//A message in the given language. A missing ID is shown as it is, so it is easy to spot.
+pub fn translate(locale: Locale, id: &str, args: &[(&str, String)]) -> String
+{
+    fill(lookup(locale, id).unwrap_or(id), args)
+}
+
+pub fn tr(id: &str, args: &[(&str, String)]) -> String
+{
+    translate(locale(), id, args)
+}
+
+//t!("pet.fed", name = pet.name(), food = "Bone") looks up a message in the chosen language
+#[macro_export]
+macro_rules! t
+{
+    ($id:expr) =>
+    {
+        $crate::modules::locale::tr($id, &[])
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) =>
+    {
+        $crate::modules::locale::tr($id, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+//The ID of a food, game or species: its English name in lower case with underscores
+fn name_id(prefix: &str, name: &str) -> String
+{
+    format!("{}.{}", prefix, name.to_lowercase().replace(' ', "_"))
+}
+
+//The shown name of something stored by its English name
+pub fn translate_name(locale: Locale, prefix: &str, name: &str) -> String
+{
+    lookup(locale, &name_id(prefix, name)).unwrap_or(name).to_string()
+}
+
+//Like translate_name, with the text to show when no catalog has it
+pub fn translate_text(prefix: &str, name: &str, english: &str) -> String
+{
+    lookup(locale(), &name_id(prefix, name)).unwrap_or(english).to_string()
+}
+
+pub fn food_name(food: &str) -> String
+{
+    translate_name(locale(), "food", food)
+}
+
+pub fn game_name(game: &str) -> String
+{
+    translate_name(locale(), "game", game)
+}
+
+pub fn kind_name(pet_kind: &PetKind) -> String
+{
+    translate_name(locale(), "kind", pet_kind.to_string())
+}
+
+pub fn recipe_name(recipe: &str) -> String
+{
+    translate_name(locale(), "recipe", recipe)
+}
+
+//What the player typed, as the English name it stands for. Both names are accepted, in any case.
+pub fn find_name(input: &str, names: &[String], translated: impl Fn(&str) -> String) -> Option<String>
+{
+    let input = input.trim().to_lowercase();
+
+    names
+        .iter()
+        .find(|name| name.to_lowercase() == input || translated(name).to_lowercase() == input)
+        .cloned()
+}
@@ -1,11 +1,34 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 use crate::modules::games::GameEffect;
-use crate::modules::food::get_food_for_pet;
+use crate::modules::food::{get_food_for_pet, FoodEffect, FoodGroup};
+use crate::modules::recipes::Recipe;
+use crate::modules::clock::{GameClock, Home, Phase};
+use crate::modules::genetics::{Genes, Trait};
+use crate::modules::rng::GameRng;
+use crate::modules::animation::{frames, play, Animation, ANIMATION_LENGTH};
+use crate::modules::skins::skin_frames;
+use crate::modules::display::{self, stat_line, DisplayMode, Stat};
+use crate::modules::locale::{find_name, food_name, game_name, kind_name, recipe_name};
+use crate::modules::rules::{rules, Difficulty, Rules};
+use crate::t;
 
-#[derive(Serialize, Deserialize, Clone)]
+//Prints a message about the pet unless it is being simulated quietly
+macro_rules! say 
+{
+    ($pet:expr, $($arg:tt)*) => 
+    {
+        if !$pet.quiet 
+        {
+            println!($($arg)*);
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum PetKind
 {
     Axalotl,
@@ -20,6 +43,7 @@ pub enum PetKind
 //Converting enum to str and opposite
 impl PetKind
 {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> 
     {
         match s.to_lowercase().as_str() 
@@ -50,7 +74,7 @@ impl PetKind
     }
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 
 pub struct Pet
 {
@@ -62,24 +86,101 @@ pub struct Pet
     happiness: u8,
     energy: u8,
     satiation: u8,
-}
 
+    //Food groups of the last meals, oldest first
+    #[serde(default)]
+    diet: VecDeque<FoodGroup>,
+
+    #[serde(default)]
+    asleep: bool,
+
+    //Ingredients in the kitchen, used up by cooking and restocked every morning
+    #[serde(default)]
+    pantry: HashMap<String, u8>,
+
+    //The day of the home's clock the pantry was last restocked on
+    #[serde(default)]
+    pantry_day: u32,
+
+    #[serde(default)]
+    genes: Genes,
+
+    #[serde(default)]
+    age: u32, //In-game minutes
+
+    #[serde(default)]
+    egg_minutes: u32, //In-game minutes left until the egg hatches
+
+    //The random sequence of the session the pet was last saved in, so loading it carries on
+    #[serde(default)]
+    rng: Option<GameRng>,
+
+    //Name of a custom skin in skins/, None for the built-in art
+    #[serde(default)]
+    skin: Option<String>,
+
+    #[serde(default)]
+    difficulty: Difficulty,
+
+    //Ticks since the stats last dropped, for difficulties where they drop less often
+    #[serde(default)]
+    decay_ticks: u8,
+
+    //Food left out while the pet is alone, eaten gradually during leave_pet_alone
+    #[serde(skip)]
+    bowl: Vec<(String, FoodEffect)>,
+
+    //Quiet pets don't print anything, used for headless simulations
+    #[serde(skip)]
+    quiet: bool,
+}
 
 //Implementing default Pet:
 impl Pet
 {
     pub fn new(name: String, kind: PetKind) -> Self
     {
-        Self
+        let rules = rules();
+        let genes = Genes::founder(&kind);
+
+        let mut pet = Self
         {
             name,
             kind,
-            lives: 1,
-            health: 10,
-            happiness: 10,
-            energy: 10,
-            satiation: 10,
-        }
+            lives: rules.lives,
+            health: rules.full_stats,
+            happiness: rules.full_stats,
+            energy: rules.full_stats,
+            satiation: rules.full_stats,
+            diet: VecDeque::new(),
+            asleep: false,
+            pantry: HashMap::new(),
+            pantry_day: 0,
+            genes,
+            age: 0,
+            egg_minutes: 0,
+            rng: None,
+            skin: None,
+            difficulty: Difficulty::Classic,
+            decay_ticks: 0,
+            bowl: Vec::new(),
+            quiet: false,
+        };
+
+        pet.restock();
+        pet
+    }
+
+    //Offspring start as an egg and their genes shape their starting stats
+    pub fn from_egg(name: String, kind: PetKind, genes: Genes) -> Self
+    {
+        let mut pet = Self::new(name, kind);
+
+        pet.set_energy(genes.energy_modifier);
+        pet.set_happiness(genes.happiness_modifier);
+        pet.genes = genes;
+        pet.egg_minutes = Self::EGG_MINUTES;
+        pet
     }
 }
 
@@ -123,12 +224,83 @@ impl Pet
         self.happiness
     }
 
+    pub fn diet(&self) -> &VecDeque<FoodGroup> 
+    {
+        &self.diet
+    }
+
+    pub fn genes(&self) -> &Genes 
+    {
+        &self.genes
+    }
+
+    pub fn age(&self) -> u32 
+    {
+        self.age
+    }
+
+    //The random sequence saved with the pet, None if it was never saved in a session
+    pub fn rng(&self) -> Option<&GameRng> 
+    {
+        self.rng.as_ref()
+    }
+
+    //1 if the pet has a trait that adds a point to a stat
+    fn bonus(&self, personality: Trait) -> u8 
+    {
+        self.genes.has(personality) as u8
+    }
+
+    pub fn skin(&self) -> Option<&str> 
+    {
+        self.skin.as_deref()
+    }
+
+    pub fn difficulty(&self) -> Difficulty 
+    {
+        self.difficulty
+    }
+
+    //The frames of an animation in the pet's skin
+    pub fn art(&self, animation: Animation) -> Arc<[String]> 
+    {
+        skin_frames(self.skin.as_deref(), &self.kind, animation)
+    }
+
     //Setters:
 
-    //Unfortunately nothing has infinite health so the health caps at 10 :(
+    pub fn set_quiet(&mut self, quiet: bool) 
+    {
+        self.quiet = quiet;
+    }
+
+    pub fn set_rng(&mut self, rng: GameRng) 
+    {
+        self.rng = Some(rng);
+    }
+
+    pub fn set_skin(&mut self, skin: Option<String>) 
+    {
+        self.skin = skin;
+    }
+
+    //Only for new pets, as it also sets the number of lives
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) 
+    {
+        self.difficulty = difficulty;
+        self.lives = self.rules().lives;
+    }
+
+    //The rules this pet is played by, adjusted for its difficulty
+    pub fn rules(&self) -> Rules 
+    {
+        rules().for_difficulty(self.difficulty)
+    }
+
+    //Unfortunately nothing has infinite health so the health caps at max_health (10) :(
     pub fn set_health(&mut self, value: i8) 
     {
-        let new_health = self.health as i8 + value;
+        let new_health = self.health as i16 + value as i16;
     
         if new_health < 0 
         {
@@ -136,14 +308,14 @@ impl Pet
         } 
         else 
         {
-            self.health = if new_health > 10 {10} else {new_health} as u8;
+            self.health = new_health.min(self.rules().max_health as i16) as u8;
         }
     }
     
-    //If the pet's happines is between 11 and 15 it is overjoyed
+    //If the pet's happines is above full it is overjoyed, up to max_happiness (15)
     pub fn set_happiness(&mut self, value: i8) 
     {
-        let new_happiness = self.happiness as i8 + value;
+        let new_happiness = self.happiness as i16 + value as i16;
 
         if new_happiness < 0
         {
@@ -151,15 +323,15 @@ impl Pet
         }
         else
         {
-            self.happiness = if new_happiness <= 15 {new_happiness} else {15} as u8;
+            self.happiness = new_happiness.min(self.rules().max_happiness as i16) as u8;
         }   
     }
 
-    //Nobody can have infinite energy so we can't increase it infinitely but it caps at 15 if the energy is between 10 and 15
-    //the pet is hyperenergetic and needs some activities.
+    //Nobody can have infinite energy so we can't increase it infinitely but it caps at max_energy (15). If the energy is
+    //above full the pet is hyperenergetic and needs some activities.
     pub fn set_energy(&mut self, value: i8) 
     {
-        let new_energy = self.energy as i8 + value;
+        let new_energy = self.energy as i16 + value as i16;
 
         if new_energy < 0
         {
@@ -167,14 +339,14 @@ impl Pet
         }
         else
         {
-            self.energy = if new_energy <= 15 {new_energy} else {15} as u8;
+            self.energy = new_energy.min(self.rules().max_energy as i16) as u8;
         }
     }
 
     //If pet is fed too much the satiation increases and leads to obesity
     pub fn set_satiation(&mut self, value: i8) 
     {
-        let new_satiation = self.satiation as i8 + value;
+        let new_satiation = self.satiation as i16 + value as i16;
         
         if new_satiation < 0
         {
@@ -182,7 +354,7 @@ impl Pet
         }
         else
         {
-            self.satiation = new_satiation as u8;
+            self.satiation = new_satiation.min(u8::MAX as i16) as u8;
         }
     }
 }
@@ -203,7 +375,7 @@ impl Pet
         let serialized = serde_json::to_string_pretty(&self)?;
 
         fs::write(&filename, serialized)?;
-        println!("Pet saved to {}", filename);
+        say!(self, "{}", t!("pet.saved", file = filename));
         Ok(())
     }
 
@@ -217,18 +389,13 @@ impl Pet
 
         if pet.lives == 0 
         {
-            eprintln!(
-                "Pet {} is dead (0 lives). Removing file {} and aborting load.",
-                pet.name, filename
-            );
+            eprintln!("{}", t!("pet.dead_on_load", name = pet.name, file = filename));
             let _ = fs::remove_file(&filename); 
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Cannot load dead pet, file removed."
-            ));
+            return Err(io::Error::other(t!("pet.dead_not_loaded")));
         }
         
-        println!("Pet loaded from {}", filename);
+        //On stderr, so that the JSON printed by the command line stays clean
+        eprintln!("{}", t!("pet.loaded_from", file = filename));
         Ok(pet)
     }
 }
@@ -238,17 +405,17 @@ pub fn is_dead(pet: &Pet) -> bool
     {
         if pet.lives == 0 
         {
-            println!("{} has 0 lives left and is now dead.", pet.name);
+            say!(pet, "{}", t!("pet.dead", name = pet.name));
             
             let path = format!("pets/{}.json", pet.name);
 
             if let Err(e) = fs::remove_file(&path) 
             {
-                println!("Warning: could not remove file '{}': {}", path, e);
+                say!(pet, "{}", t!("pet.file_not_removed", file = path, error = e));
             } 
             else 
             {
-                println!("Pet file '{}' removed.", path);
+                say!(pet, "{}", t!("pet.file_removed", file = path));
             }
     
             return true; 
@@ -257,14 +424,18 @@ pub fn is_dead(pet: &Pet) -> bool
     }
 
 //List of previous sessions
+//The names of the saved pets. Nothing is printed, as the list is also the output of `list`.
 pub fn pets_list() -> io::Result<Vec<String>> 
 {
-    let directory = "pets";
+    pets_in("pets")
+}
+
+pub fn pets_in(directory: &str) -> io::Result<Vec<String>> 
+{
     let mut pets = Vec::new();
 
     if fs::metadata(directory).is_err() 
     {
-        println!("The pets directory does not exist.");
         return Ok(vec![]);
     }
 
@@ -283,298 +454,680 @@ pub fn pets_list() -> io::Result<Vec<String>>
         }
     }
 
-    if pets.is_empty() 
-    {
-        println!("No pets found in the directory.");
-    }
-
     Ok(pets)
 }
 
 //Implementation for pet's pictures
 impl PetKind 
 {
+    //The first idle frame from data/animations
     pub fn ascii_art(&self) -> &str 
     {
-        match self 
-        {
-            PetKind::Axalotl => "
-                  -,__
-                     \\\\
-   .      ,          : :
- -,_:____:_.'       :  |
--,_(• _ •)__,  __,-'   /
-    '===='----'    ___/
-      '--_____,.--',;
-     -,' -,'     -'
-",
-            PetKind::Cat => "
-               /\\\\_
-              (` ..\\
-         __,.-\" =__Y=
-       .\"        )
- _    /   ,    \\/\\_
-((____|    )_-\\ \\_-`
-`-----'`-----` `--`
-",
-            PetKind::Dog => "
-     _  
- __/_  `.  .-\"\"\"-.
- \\_,` | \\-'  /   )`-')
-  \"\") `\"`    \\  ((`\"`
- ___Y  ,    .'7 /|
-(_,___/...-` (_/_/
-",
-            PetKind::Monkey => "
-     _____
-   _/.-.-.\\_       
-  /|( o o )|\\      
- | //  \"  \\\\ |     
-/ / \\'---'/ \\ \\     
-\\ \\_/`\"\"\"`\\_/ /    
- \\___________/
-",
-            PetKind::Parrot => "
-  (((  
-  `-`-.
-  '( @ >
-   _) (
-  /    )
- /_,'  / 
-   \\  / 
-===m\"\"m===
-",
-            PetKind::Rabbit => "
-         ,\\
-         \\\\\\,_
-          \\` ,\\
-     __,.-\" =__)
-   .\"        )
-,_/   ,    \\/\\_
-\\_|    )_-\\ \\_-`
-   `-----` `--`
-",
-            PetKind::Squirrel => r#"
-(\__/)  .~    ~. ))
- /O O  ./      .'
-{O__,   \    {
-  / .  . )    \
-  |-| '-' \    }
- .(   _(   )_.'
-'---.~_ _ _&
-"#,
-        }
+        &frames(self, Animation::Idle)[0]
     }
 }
 
 //Printing pet's stats
 impl Pet 
 {
-    pub fn display_stats(&self) 
+    //One line overview used when several pets are looked after at once
+    pub fn summary(&self) -> String 
     {
-        println!("{} the {}:", self.name, self.kind.to_string());
-        println!("{}", self.kind.ascii_art());
+        let summary = t!(
+            "pet.summary",
+            name = self.name,
+            kind = kind_name(&self.kind),
+            lives = self.lives,
+            health = self.health,
+            happiness = self.happiness,
+            energy = self.energy,
+            satiation = self.satiation
+        );
 
-        print!("Lives: ");
-        for _ in 0..self.lives 
+        if self.asleep 
         {
-            print!("♥ ");
+            t!("pet.summary_sleeping", summary = summary)
+        } 
+        else 
+        {
+            summary
         }
-        println!();
+    }
+
+    pub fn display_stats(&self, home: &Home) 
+    {
+        self.print_stats(home, || println!("\n{}\n", self.art(Animation::Idle)[0]));
+    }
+
+    //Shows the stats with the art animated, e.g. while the pet eats
+    pub fn display_animated(&self, home: &Home, animation: Animation, fps: u32) 
+    {
+        self.print_stats(home, || {
+            println!();
+            play(&self.art(animation), fps, ANIMATION_LENGTH);
+            println!();
+        });
+    }
 
-        print!("Health: ");
-        for _ in 0..self.health 
+    fn print_stats(&self, home: &Home, print_art: impl FnOnce()) 
+    {
+        let settings = display::settings();
+        let fancy = settings.mode == DisplayMode::Fancy;
+
+        println!("{}", t!("stats.header", name = self.name, kind = kind_name(&self.kind)));
+
+        if self.is_egg() 
         {
-            print!("█ ");
+            println!("{}{}", if fancy {"🥚 "} else {""}, t!("stats.egg", name = self.name, minutes = self.egg_minutes));
+            println!();
+            return;
         }
-        println!();
 
-        print!("Happiness: ");
-        for _ in 0..self.happiness 
+        //A screen reader would read the art out character by character
+        if settings.mode != DisplayMode::ScreenReader 
         {
-            print!("😊");
+            print_art();
         }
-        println!();
+        println!(
+            "{}",
+            t!(
+                "stats.age",
+                days = self.age / (24 * 60),
+                hours = self.age / 60 % 24,
+                stage = if self.is_adult() {t!("stats.adult")} else {t!("stats.young")},
+                colour = format!("{:?}", self.genes.colour),
+                traits = if self.genes.traits.is_empty() {t!("stats.no_traits")} else {format!("{:?}", self.genes.traits)}
+            )
+        );
+
+        println!("{}", t!("stats.difficulty", difficulty = self.difficulty.name()));
 
-        print!("Energy: ");
-        for _ in 0..self.energy 
+        let clock = &home.clock;
+        let phase = clock.phase().name();
+        println!(
+            "{}",
+            t!(
+                "stats.time",
+                day = clock.day() + 1,
+                time = format!("{:02}:{:02}", clock.hour(), clock.minute()),
+                phase = if fancy {format!("{} {}", clock.phase().icon(), phase)} else {phase},
+                lights = home.lights()
+            )
+        );
+
+        if self.asleep 
         {
-            print!("⚡");
+            println!("{}{}", if fancy {"💤 "} else {""}, t!("stats.sleeping", name = self.name));
         }
-        println!();
 
-        print!("Satiation: ");
-        for _ in 0..self.satiation 
+        let rules = self.rules();
+        for (stat, value) in [
+            (Stat::Lives, self.lives),
+            (Stat::Health, self.health),
+            (Stat::Happiness, self.happiness),
+            (Stat::Energy, self.energy),
+            (Stat::Satiation, self.satiation),
+        ] 
         {
-            print!("🍎");
+            println!("{}", stat_line(stat, value, &rules, &settings));
         }
-        println!();
 
         println!();
     }
 }
 
-//Implementing sleeping
+//Implementing sleeping - sleep lasts several ticks, energy recovers gradually and the pet
+//wakes up by itself once rested. Waking it up early makes it grumpy.
 impl Pet
 {
+    pub fn is_asleep(&self) -> bool 
+    {
+        self.asleep
+    }
+
     pub fn sleep(&mut self)
     {   
-        self.energy = 10;
+        self.asleep = true;
+        say!(self, "{}", t!("pet.fell_asleep", name = self.name));
+    }
+
+    fn sleep_tick(&mut self) 
+    {
+        let rules = self.rules();
+        self.set_energy((rules.sleep_energy_gain + self.bonus(Trait::Lazy)) as i8);
+        self.set_satiation(-(rules.sleep_satiation_cost as i8));
 
-        if self.satiation >= 3
+        if self.energy >= rules.wake_energy 
         {
-            self.satiation = self.satiation - 3;
+            self.wake_up();
         }
-        else
+    }
+
+    fn wake_up(&mut self) 
+    {
+        self.asleep = false;
+
+        //Only the energy comes back if sleep doesn't cheer the pet up, e.g. for hardcore pets.
+        //Otherwise a sad pet wakes up at least half happy.
+        let rules = self.rules();
+        if rules.sleep_cheers_up 
         {
-            self.satiation = 0;
+            let rested = rules.full_stats / 2;
+
+            if self.happiness < rested 
+            {
+                self.happiness = rested;
+            } 
+            else 
+            {
+                self.happiness = (self.happiness + 1).min(rules.full_stats);
+            }
+        }
+
+        say!(self, "{}", t!("pet.woke_up", name = self.name, energy = self.energy, happiness = self.happiness));
+    }
+
+    pub fn wake_early(&mut self) 
+    {
+        if self.asleep 
+        {
+            self.asleep = false;
+            let grumpiness = self.rules().grumpiness + self.bonus(Trait::Grumpy);
+            self.set_happiness(-(grumpiness as i8));
+            say!(self, "{}", t!("pet.woken_early", name = self.name, grumpiness = grumpiness));
+        }
+    }
+}
+
+//Implementing growing up - eggs hatch after a few hours and pets are adults after three days
+impl Pet
+{
+    const EGG_MINUTES: u32 = 6 * 60;
+    const ADULT_AGE: u32 = 3 * 24 * 60;
+
+    pub fn is_egg(&self) -> bool 
+    {
+        self.egg_minutes > 0
+    }
+
+    pub fn is_adult(&self) -> bool 
+    {
+        self.age >= Self::ADULT_AGE
+    }
+
+    fn pass_minutes(&mut self, minutes: u32) 
+    {
+        self.age += minutes;
+
+        if self.is_egg() 
+        {
+            self.egg_minutes = self.egg_minutes.saturating_sub(minutes);
+
+            if !self.is_egg() 
+            {
+                say!(self, "{}", t!("pet.hatched", name = self.name));
+            }
+        }
+    }
+}
+
+//Implementing the passing of time and the day/night cycle
+impl Pet
+{
+    //Eggs only incubate, nothing else happens to them. The home's clock has already been
+    //moved on by the session.
+    pub fn tick(&mut self, home: &Home) 
+    {
+        let was_egg = self.is_egg();
+        self.pass_minutes(GameClock::MINUTES_PER_TICK);
+        self.restock_in_the_morning(home);
+
+        if was_egg 
+        {
+            return;
         }
 
-        if self.happiness < 5 
+        if self.asleep 
         {
-            self.happiness = 5;
+            self.sleep_tick();
         } 
         else 
         {
-            self.happiness = (self.happiness + 1).min(10);
+            self.decay();
         }
 
-        println!("Energy restored to 10, satiation decreased by 3, and happiness adjusted.",);
+        self.night_effects(home);
+    }
+
+    fn decay(&mut self) 
+    {
+        let rules = self.rules();
+
+        self.decay_ticks = self.decay_ticks.saturating_add(1);
+        if self.decay_ticks < rules.decay_every 
+        {
+            return;
+        }
+        self.decay_ticks = 0;
+
+        let decay = rules.decay as i8;
+        self.set_happiness(-decay);
+        self.set_energy(-decay);
+        self.set_satiation(-decay);
+    }
+
+    //Staying up at night is tiring and the lights keep the pet from resting
+    fn night_effects(&mut self, home: &Home) 
+    {
+        if home.clock.phase() != Phase::Night 
+        {
+            return;
+        }
+
+        let decay = self.rules().decay as i8;
+
+        if !self.asleep && !self.genes.has(Trait::Hardy) 
+        {
+            self.set_energy(-decay);
+        }
+
+        if home.lights_on && !self.genes.has(Trait::Cheerful) 
+        {
+            self.set_happiness(-decay);
+        }
     }
 }
 
-//Implementing pet interactions - feeding and playing
+//Implementing pet interactions - feeding, cooking and playing
 impl Pet
 {
-    pub fn feed(&mut self, food_name: &str, food_map: &HashMap<String, (u8, u8)>) -> bool
+    const PANTRY_STOCK: u8 = 2;
+
+    pub fn feed(&mut self, food_id: &str, food_map: &HashMap<String, FoodEffect>) -> bool
     {
-        if let Some(&(satiation_gain, energy_gain)) = food_map.get(food_name) 
+        if let Some(food) = food_map.get(food_id) 
         {
-            self.set_satiation(satiation_gain as i8);
-            self.set_energy(energy_gain as i8);
+            let satiation = food.satiation_gain + self.bonus(Trait::Greedy);
+            self.set_satiation(satiation as i8);
+            self.set_energy(food.energy_gain as i8);
+            self.record_meal(food.group);
 
-            println!(
-                "{} was fed {}. Satiation +{}, Energy +{}.",
-                self.name,
-                food_name,
-                satiation_gain,
-                energy_gain
+            say!(self, 
+                "{}",
+                t!("pet.fed", name = self.name, food = food_name(food_id), satiation = satiation, energy = food.energy_gain)
             );
-            return true;
+            self.check_diet();
+            true
         } 
         else 
         {
-            println!("{} cannot eat {}. Food not recognized.", self.name, food_name);
-            return false;
+            say!(self, "{}", t!("pet.unknown_food", name = self.name, food = food_id));
+            false
+        }
+    }
+    
+    pub fn pantry(&self) -> &HashMap<String, u8> 
+    {
+        &self.pantry
+    }
+
+    //The first time the pet sees the home's clock in the daytime of a new day
+    fn restock_in_the_morning(&mut self, home: &Home) 
+    {
+        if home.clock.day() > self.pantry_day && home.clock.phase() == Phase::Day 
+        {
+            self.pantry_day = home.clock.day();
+            self.restock();
+        }
+    }
+
+    //Every food the pet can eat is stocked up to PANTRY_STOCK
+    fn restock(&mut self) 
+    {
+        for food_name in get_food_for_pet(&self.kind) 
+        {
+            let count = self.pantry.entry(food_name).or_default();
+            *count = (*count).max(Self::PANTRY_STOCK);
+        }
+    }
+
+    fn has_ingredients(&self, recipe: &Recipe) -> bool 
+    {
+        recipe.ingredients.iter().all(|ingredient| {
+            let needed = recipe.ingredients.iter().filter(|other| *other == ingredient).count();
+            self.pantry.get(ingredient).copied().unwrap_or(0) as usize >= needed
+        })
+    }
+
+    pub fn cook(&mut self, recipe_id: &str, recipes_map: &HashMap<String, Recipe>, food_map: &HashMap<String, FoodEffect>) -> bool
+    {
+        let recipe = match recipes_map.get(recipe_id) 
+        {
+            Some(recipe) if recipe.is_for(&self.kind) => recipe,
+            Some(_) => 
+            {
+                say!(self, "{}", t!("pet.recipe_wrong_kind", recipe = recipe_name(recipe_id), kind = kind_name(&self.kind)));
+                return false;
+            }
+            None => 
+            {
+                say!(self, "{}", t!("pet.unknown_recipe", name = self.name, recipe = recipe_name(recipe_id)));
+                return false;
+            }
+        };
+
+        match recipe.effect(food_map) 
+        {
+            Some(dish) if self.has_ingredients(recipe) => 
+            {
+                for ingredient in &recipe.ingredients 
+                {
+                    if let Some(count) = self.pantry.get_mut(ingredient) 
+                    {
+                        *count -= 1;
+                    }
+                }
+
+                let satiation = dish.satiation_gain + self.bonus(Trait::Greedy);
+                self.set_satiation(satiation as i8);
+                self.set_energy(dish.energy_gain as i8);
+
+                for ingredient in &recipe.ingredients 
+                {
+                    if let Some(food) = food_map.get(ingredient) 
+                    {
+                        self.record_meal(food.group);
+                    }
+                }
+
+                say!(self, 
+                    "{}",
+                    t!(
+                        "pet.cooked",
+                        recipe = recipe_name(&recipe.name),
+                        ingredients = recipe.ingredients.iter().map(|ingredient| food_name(ingredient)).collect::<Vec<_>>().join(" + "),
+                        name = self.name,
+                        satiation = satiation,
+                        energy = dish.energy_gain
+                    )
+                );
+                self.check_diet();
+                true
+            }
+            _ => 
+            {
+                say!(self, "{}", t!("pet.missing_ingredient", recipe = recipe.name));
+                false
+            }
         }
     }
     
-    pub fn play(&mut self, game_name: &str, games_map: &HashMap<String, GameEffect>) -> bool
+    pub fn play(&mut self, game_id: &str, games_map: &HashMap<String, GameEffect>, home: &Home) -> bool
     {
-        if let Some(game) = games_map.get(game_name) 
+        if let Some(game) = games_map.get(game_id) 
         {
-            if self.energy >= game.energy_cost && self.satiation >= game.satiation_cost 
+            if game.daytime_only && home.clock.phase() == Phase::Night 
+            {
+                say!(self, "{}", t!("pet.too_dark", game = game_name(game_id)));
+                false
+            } 
+            else if self.energy >= game.energy_cost && self.satiation >= game.satiation_cost 
             {
-                println!(
-                    "Happiness +{}, Energy -{}, Satiation -{}.",
-                    game.happiness_increase,
-                    game.energy_cost,
-                    game.satiation_cost,
+                let happiness = game.happiness_increase + self.bonus(Trait::Playful);
+                say!(self, 
+                    "{}",
+                    t!("pet.played", happiness = happiness, energy = game.energy_cost, satiation = game.satiation_cost)
                 );
 
-                self.set_happiness(game.happiness_increase as i8);
+                self.set_happiness(happiness as i8);
                 self.set_energy(-(game.energy_cost as i8));
                 self.set_satiation(-(game.satiation_cost as i8));
 
-                return true;
+                true
             } 
             else 
             {
-                println!(
-                    "{} does not have enough energy or satiation to play {}.",
-                    self.name,
-                    game_name
-                );
+                say!(self, "{}", t!("pet.too_tired_to_play", name = self.name, game = game_name(game_id)));
 
-                return false;
+                false
             }
         } 
         else 
         {
-            println!("{} cannot play {}. Game not recognized.", self.name, game_name);
-            return false;
+            say!(self, "{}", t!("pet.unknown_game", name = self.name, game = game_id));
+            false
         }
     }
 }
 
+//Warnings raised by the health checks
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Warning
+{
+    SatiationHigh,
+    SatiationDangerous,
+    SatiationEmpty,
+    EnergyLow,
+    EnergyEmpty,
+}
+
+impl Warning
+{
+    //Warnings keep their names in saved files and the JSON status, this is only how they are shown
+    pub fn name(&self) -> String
+    {
+        t!(match self
+        {
+            Warning::SatiationHigh => "alert.satiation_high",
+            Warning::SatiationDangerous => "alert.satiation_dangerous",
+            Warning::SatiationEmpty => "alert.satiation_empty",
+            Warning::EnergyLow => "alert.energy_low",
+            Warning::EnergyEmpty => "alert.energy_empty",
+        })
+    }
+}
+
+//How the pet feels overall, from the most pressing problem down
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mood
+{
+    Egg,
+    Asleep,
+    Sick,
+    Hungry,
+    Tired,
+    Sad,
+    Happy,
+    Content,
+}
+
 //Implementing health checks
 impl Pet
 {
-    pub fn check_satiation(&mut self) 
+    pub fn mood(&self) -> Mood
     {
-        if self.satiation >= 11 && self.satiation <= 14 
+        let rules = self.rules();
+
+        if self.is_egg() 
         {
-            println!(
-                "Warning: {}'s satiation is high ({})! Consider playing games to reduce it.",
-                self.name,
-                self.satiation
-            );
+            Mood::Egg
         } 
-        else if self.satiation > 15 
+        else if self.asleep 
         {
-            println!(
-                "Warning: {}'s satiation ({}) is dangerously high! Health reduced by 1.",
-                self.name,
-                self.satiation
-            );
-            self.set_health(-1);
+            Mood::Asleep
+        } 
+        else if self.health <= rules.low_stat() 
+        {
+            Mood::Sick
+        } 
+        else if self.satiation <= rules.low_stat() 
+        {
+            Mood::Hungry
+        } 
+        else if self.is_tired() 
+        {
+            Mood::Tired
+        } 
+        else if self.happiness <= rules.low_stat() 
+        {
+            Mood::Sad
+        } 
+        else if self.happiness >= rules.high_stat() 
+        {
+            Mood::Happy
+        } 
+        else 
+        {
+            Mood::Content
+        }
+    }
+
+    //The warnings the health checks would raise right now, without their penalties
+    pub fn warnings(&self) -> Vec<Warning>
+    {
+        let mut warnings = Vec::new();
+
+        if self.is_overfed() 
+        {
+            warnings.push(Warning::SatiationHigh);
+        } 
+        else if self.is_dangerously_overfed() 
+        {
+            warnings.push(Warning::SatiationDangerous);
         } 
         else if self.satiation == 0 
         {
-            println!(
-                "Warning: {}'s satiation is too low (0)! Health reduced by 1.",
-                self.name
-            );
-            self.set_health(-1);
+            warnings.push(Warning::SatiationEmpty);
+        }
+
+        if self.energy == 0 
+        {
+            warnings.push(Warning::EnergyEmpty);
+        } 
+        else if self.is_tired() 
+        {
+            warnings.push(Warning::EnergyLow);
         }
+
+        warnings
+    }
+
+    fn is_overfed(&self) -> bool 
+    {
+        let rules = self.rules();
+        (rules.satiation_high..=rules.satiation_high_max).contains(&self.satiation)
+    }
+
+    fn is_dangerously_overfed(&self) -> bool 
+    {
+        self.satiation > self.rules().satiation_dangerous
     }
 
-    pub fn check_energy(&mut self) -> bool
+    //Reports the satiation warning from warnings() and applies its penalty
+    pub fn check_satiation(&mut self) -> Option<Warning>
     {
-        if self.energy <= 5 
+        let warning = self.warnings().into_iter().find(|warning| matches!(warning,
+            Warning::SatiationHigh | Warning::SatiationDangerous | Warning::SatiationEmpty));
+
+        match warning 
         {
-            if self.energy == 0 
+            Some(Warning::SatiationHigh) => 
             {
-                println!(
-                    "Warning: {}'s energy is too low (0)! Health reduced by 1.",
-                    self.name
-                );
+                say!(self, "{}", t!("warning.satiation_high", name = self.name, satiation = self.satiation));
+            }
+            Some(Warning::SatiationDangerous) => 
+            {
+                say!(self, "{}", t!("warning.satiation_dangerous", name = self.name, satiation = self.satiation));
                 self.set_health(-1);
             }
-            else
+            Some(Warning::SatiationEmpty) => 
             {
-                println!(
-                    "Warning: {}'s energy is low! Consider doing something.",
-                    self.name,
-                );
+                say!(self, "{}", t!("warning.satiation_empty", name = self.name));
+                self.set_health(-1);
             }
+            _ => {}
+        }
 
-            return true;
-        } 
-        
-        false
+        warning
+    }
+
+    pub fn is_tired(&self) -> bool 
+    {
+        self.energy <= self.rules().tired_energy
     }
 
-    pub fn is_health_zero(&mut self) 
+    //Reports the energy warning from warnings() and applies its penalty
+    pub fn check_energy(&mut self) -> Option<Warning>
+    {
+        let warning = self.warnings().into_iter().find(|warning| matches!(warning, Warning::EnergyLow | Warning::EnergyEmpty));
+
+        match warning 
+        {
+            Some(Warning::EnergyEmpty) => 
+            {
+                say!(self, "{}", t!("warning.energy_empty", name = self.name));
+                self.set_health(-1);
+            }
+            Some(Warning::EnergyLow) => 
+            {
+                say!(self, "{}", t!("warning.energy_low", name = self.name));
+            }
+            _ => {}
+        }
+
+        warning
+    }
+
+    //Returns true if the pet lost a life
+    pub fn is_health_zero(&mut self) -> bool 
     {
         if self.health == 0 && self.lives > 0 
         {
+            let full = self.rules().full_stats;
             self.lives -= 1;
-            self.health = 10;
-            self.energy = 10;
-            self.satiation = 10;
-            self.happiness = 10;
-            println!("{} lost a life! Remaining lives: {}", self.name, self.lives);
+            self.health = full;
+            self.energy = full;
+            self.satiation = full;
+            self.happiness = full;
+            say!(self, "{}", t!("pet.lost_life", name = self.name, lives = self.lives));
+            return true;
+        }
+
+        false
+    }
+}
+
+//Implementing the diet - a balanced diet heals the pet, junk food makes it ill over time.
+//The diet is checked once for every meal, so each meal counts once.
+impl Pet
+{
+    const DIET_HISTORY: usize = 6;
+
+    fn record_meal(&mut self, group: FoodGroup) 
+    {
+        self.diet.push_back(group);
+
+        if self.diet.len() > Self::DIET_HISTORY 
+        {
+            self.diet.pop_front();
+        }
+    }
+
+    fn check_diet(&mut self) 
+    {
+        let count = |group: FoodGroup| self.diet.iter().filter(|&&g| g == group).count();
+        let (protein, fruit_veg, treats) = (count(FoodGroup::Protein), count(FoodGroup::FruitVeg), count(FoodGroup::Treat));
+
+        if self.diet.len() >= 3 && treats == self.diet.len() 
+        {
+            say!(self, "{}", t!("warning.only_treats", name = self.name));
+            self.set_health(-1);
+            self.set_happiness(-1);
+        } 
+        else if protein > 0 && fruit_veg > 0 && treats * 2 <= self.diet.len() && self.health < self.rules().max_health 
+        {
+            say!(self, "{}", t!("pet.balanced_diet", name = self.name));
+            self.set_health(1);
         }
     }
 }
@@ -582,52 +1135,150 @@ impl Pet
 //We can not be with the pet for the whole time:
 impl Pet
 {
-    pub fn leave_pet_alone(&mut self, time: u8) 
+    //The pet only goes for the food in the bowl once its satiation drops below this
+    const HUNGRY_SATIATION: u8 = 8;
+
+    pub fn bowl(&self) -> &[(String, FoodEffect)] 
+    {
+        &self.bowl
+    }
+
+    //Every hour the stats decay by 1 and a hungry pet eats the next food from the bowl.
+    //Food older than its shelf life has spoiled and makes the pet sick instead of feeding it.
+    //The home is as it was when the pet was left, moving its clock on is up to the session.
+    pub fn leave_pet_alone(&mut self, time: u8, home: &Home) 
+    {
+        say!(self, "{}", t!("leave.left_alone", name = self.name, hours = time));
+
+        let (satiation, energy, happiness) = (self.satiation, self.energy, self.happiness);
+        let mut home = home.clone();
+
+        for hour in 1..=time 
+        {
+            home.clock.advance_hours(1);
+            if self.is_egg() 
+            {
+                self.pass_minutes(60);
+                continue;
+            }
+
+            if self.asleep 
+            {
+                self.sleep_tick();
+            } 
+            else 
+            {
+                self.decay();
+            }
+
+            self.pass_minutes(60);
+            self.restock_in_the_morning(&home);
+            self.night_effects(&home);
+
+            if self.satiation < Self::HUNGRY_SATIATION && !self.bowl.is_empty() 
+            {
+                let (food_id, food) = self.bowl.remove(0);
+                self.eat_left_food(&food_id, &food, hour);
+            }
+        }
+
+        say!(self, 
+            "{}",
+            t!(
+                "leave.summary",
+                satiation = format!("{:+}", self.satiation as i16 - satiation as i16),
+                energy = format!("{:+}", self.energy as i16 - energy as i16),
+                happiness = format!("{:+}", self.happiness as i16 - happiness as i16)
+            )
+        );
+
+        if !self.bowl.is_empty() 
+        {
+            say!(self, "{}", t!("leave.thrown_away", count = self.bowl.len()));
+            self.bowl.clear();
+        }
+    }
+
+    fn eat_left_food(&mut self, food_id: &str, food: &FoodEffect, hour: u8) 
     {
-        println!("You have left {} alone for {} hours.", self.name, time);
+        let spoiled = matches!(food.shelf_life, Some(shelf_life) if hour > shelf_life);
 
-        self.set_satiation(-(time as i8));
-        self.set_energy(-(time as i8));
-        self.set_happiness(-(time as i8));
+        if spoiled 
+        {
+            self.set_health(-1);
+            self.set_happiness(-1);
+            say!(self, "{}", t!("leave.ate_spoiled", hour = hour, name = self.name, food = food_name(food_id)));
+        } 
+        else 
+        {
+            self.set_satiation(food.satiation_gain as i8);
+            self.set_energy(food.energy_gain as i8);
+            self.record_meal(food.group);
+            say!(self, 
+                "{}",
+                t!("leave.ate", hour = hour, name = self.name, food = food_name(food_id), satiation = food.satiation_gain, energy = food.energy_gain)
+            );
+            self.check_diet();
+        }
+    }
+
+    pub fn leave_food(&mut self, food_id: &str, food_map: &HashMap<String, FoodEffect>) -> bool 
+    {
+        if food_map.contains_key(food_id) && !get_food_for_pet(&self.kind).iter().any(|valid| valid == food_id) 
+        {
+            say!(self, " - {}", t!("leave.bowl_inedible", food = food_name(food_id), name = self.name));
+            false
+        } 
+        else if let Some(food) = food_map.get(food_id) 
+        {
+            match food.shelf_life 
+            {
+                Some(hours) => say!(self, " - {}", t!("leave.bowl_spoils", food = food_name(food_id), satiation = food.satiation_gain, energy = food.energy_gain, hours = hours)),
+                None => say!(self, " - {}", t!("leave.bowl_keeps", food = food_name(food_id), satiation = food.satiation_gain, energy = food.energy_gain)),
+            }
 
-        println!("You left your pet alone. Satiation -{}, Energy -{}, Happiness -{}.", time, time, time);
+            self.bowl.push((food_id.to_string(), food.clone()));
+            true
+        } 
+        else 
+        {
+            say!(self, " - {}", t!("leave.bowl_unknown", food = food_id));
+            false
+        }
     }
 
-    pub fn leave_food_while_gone(&mut self, food_map: &HashMap<String, (u8, u8)>) 
+    pub fn leave_food_while_gone(&mut self, food_map: &HashMap<String, FoodEffect>) 
     {
     
-        println!("Foods available for {}:", self.kind().to_string());
+        println!("{}", t!("food.available", kind = kind_name(self.kind())));
         let valid_foods = get_food_for_pet(self.kind());
-        for food in &valid_foods 
+        for food_id in &valid_foods 
         {
-            if let Some(&(sat, en)) = food_map.get(food) 
+            if let Some(food) = food_map.get(food_id) 
             {
-                println!(" - {} (Satiation +{}, Energy +{})", food, sat, en);
+                println!(" - {}", t!("food.effect", food = food_name(food_id), satiation = food.satiation_gain, energy = food.energy_gain));
             } 
             else 
             {
-                println!(" - {}", food);
+                println!(" - {}", food_name(food_id));
             }
         }
     
-        println!("Enter the names of foods to leave (comma-separated):");
+        println!("{}", t!("leave.enter_foods"));
         let mut input = String::new();
         io::stdin().read_line(&mut input).expect("Failed to read input");
     
-        let foods_left: Vec<String> = input.trim().split(',').map(|s| s.trim().to_string()).collect();
+        //The foods can be typed in English or in the chosen language
+        let foods_left: Vec<String> = input
+            .trim()
+            .split(',')
+            .map(|s| find_name(s, &valid_foods, food_name).unwrap_or(s.trim().to_string()))
+            .collect();
     
-        for food_name in foods_left 
+        println!("{}", t!("leave.bowl", name = self.name));
+        for food_id in foods_left 
         {
-            if let Some(&(sat, en)) = food_map.get(&food_name) 
-            {
-                self.set_satiation(sat as i8);
-                self.set_energy(en as i8);
-                println!(" - {} (Satiation +{}, Energy +{})", food_name, sat, en);
-            } 
-            else 
-            {
-                println!(" - {} (Unknown food, not applied)", food_name);
-            }
+            self.leave_food(&food_id, food_map);
         }
     }
 }
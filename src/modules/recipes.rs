@@ -0,0 +1,87 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::modules::pet::PetKind;
+use crate::modules::food::{get_food_for_pet, FoodEffect};
+
+//Recipes are defined in data/recipes.json. A dish combines the effects of its ingredients
+//and adds its own bonus on top. Recipes with no kinds listed can be cooked for every pet that
+//eats all of their ingredients.
+
+const RECIPES_DATA: &str = include_str!("../../data/recipes.json");
+
+#[derive(Debug, Deserialize)]
+
+pub struct Recipe
+{
+    pub name: String,
+    pub ingredients: Vec<String>,
+    pub satiation_bonus: u8,
+    pub energy_bonus: u8,
+    #[serde(default)]
+    pub kinds: Vec<PetKind>,
+}
+
+impl Recipe
+{
+    pub fn is_for(&self, pet_kind: &PetKind) -> bool
+    {
+        let foods = get_food_for_pet(pet_kind);
+
+        (self.kinds.is_empty() || self.kinds.contains(pet_kind)) && self.ingredients.iter().all(|ingredient| foods.contains(ingredient))
+    }
+
+    //The dish spoils as fast as its most perishable ingredient and belongs to the food group
+    //of its main (first) ingredient
+    pub fn effect(&self, food_map: &HashMap<String, FoodEffect>) -> Option<FoodEffect>
+    {
+        let main_ingredient = food_map.get(self.ingredients.first()?)?;
+
+        let mut dish = FoodEffect
+        {
+            satiation_gain: self.satiation_bonus,
+            energy_gain: self.energy_bonus,
+            shelf_life: None,
+            group: main_ingredient.group,
+        };
+
+        for ingredient in &self.ingredients
+        {
+            let food = food_map.get(ingredient)?;
+
+            dish.satiation_gain = dish.satiation_gain.saturating_add(food.satiation_gain);
+            dish.energy_gain = dish.energy_gain.saturating_add(food.energy_gain);
+            dish.shelf_life = match (dish.shelf_life, food.shelf_life)
+            {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+
+        Some(dish)
+    }
+}
+
+pub fn initialize_recipes() -> HashMap<String, Recipe>
+{
+    let recipes: Vec<Recipe> = serde_json::from_str(RECIPES_DATA).expect("data/recipes.json is not valid");
+    let mut recipes_map = HashMap::new();
+
+    for recipe in recipes
+    {
+        recipes_map.insert(recipe.name.clone(), recipe);
+    }
+
+    recipes_map
+}
+
+pub fn get_recipes_for_pet(pet_kind: &PetKind, recipes_map: &HashMap<String, Recipe>) -> Vec<String>
+{
+    let mut names: Vec<String> = recipes_map
+        .values()
+        .filter(|recipe| recipe.is_for(pet_kind))
+        .map(|recipe| recipe.name.clone())
+        .collect();
+
+    names.sort();
+    names
+}
@@ -0,0 +1,428 @@
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use crate::modules::pet::PetKind;
+use crate::modules::food::get_food_for_pet;
+use crate::modules::games::get_games;
+use crate::modules::locale::{food_name, game_name, recipe_name};
+use crate::t;
+
+//The command line of the game. Commands are words like "feed bone" or "play fetch", the
+//numbers of the old menu still work, and names may be misspelled or typed in any case as
+//long as it is clear what was meant. Tab completes commands, foods, games and recipes.
+
+pub const HISTORY_FILE: &str = "repl_history.txt";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action
+{
+    Feed,
+    Play,
+    Sleep,
+    Leave,
+    Cook,
+    Lights,
+    Switch,
+    Add,
+    Pets,
+    Meet,
+    Breed,
+    FamilyTree,
+    Autopilot,
+    Log,
+    Undo,
+    Actions,
+    History,
+    Trends,
+    Skin,
+    Status,
+    Menu,
+    Help,
+    Quit,
+}
+
+impl Action
+{
+    pub const ALL: [Action; 23] = [
+        Action::Feed, Action::Play, Action::Sleep, Action::Leave, Action::Cook, Action::Lights, Action::Switch, Action::Add,
+        Action::Pets, Action::Meet, Action::Breed, Action::FamilyTree, Action::Autopilot, Action::Log, Action::Undo,
+        Action::Actions, Action::History, Action::Trends, Action::Skin, Action::Status, Action::Menu, Action::Help, Action::Quit,
+    ];
+
+    pub fn name(&self) -> &str
+    {
+        match self
+        {
+            Action::Feed => "feed",
+            Action::Play => "play",
+            Action::Sleep => "sleep",
+            Action::Leave => "leave",
+            Action::Cook => "cook",
+            Action::Lights => "lights",
+            Action::Switch => "switch",
+            Action::Add => "add",
+            Action::Pets => "pets",
+            Action::Meet => "meet",
+            Action::Breed => "breed",
+            Action::FamilyTree => "tree",
+            Action::Autopilot => "autopilot",
+            Action::Log => "log",
+            Action::Undo => "undo",
+            Action::Actions => "actions",
+            Action::History => "history",
+            Action::Trends => "trends",
+            Action::Skin => "skin",
+            Action::Status => "status",
+            Action::Menu => "menu",
+            Action::Help => "help",
+            Action::Quit => "quit",
+        }
+    }
+
+    //The number of the action in the game menu
+    pub fn number(&self) -> Option<u8>
+    {
+        match self
+        {
+            Action::Status | Action::Menu | Action::Help => None,
+            Action::Quit => Some(0),
+            action => Self::ALL.iter().position(|other| other == action).map(|index| index as u8 + 1),
+        }
+    }
+
+    //What the action can be given, for the help
+    fn argument(&self) -> Option<String>
+    {
+        match self
+        {
+            Action::Feed => Some(t!("repl.argument_food")),
+            Action::Play => Some(t!("repl.argument_game")),
+            Action::Cook => Some(t!("repl.argument_recipe")),
+            Action::Switch => Some(t!("repl.argument_pet")),
+            Action::Help => Some(t!("repl.argument_command")),
+            _ => None,
+        }
+    }
+
+    pub fn description(&self) -> String
+    {
+        t!(match self
+        {
+            Action::Feed => "menu.feed",
+            Action::Play => "menu.play",
+            Action::Sleep => "menu.sleep",
+            Action::Leave => "menu.leave",
+            Action::Cook => "menu.cook",
+            Action::Lights => "menu.lights",
+            Action::Switch => "menu.switch",
+            Action::Add => "menu.add",
+            Action::Pets => "menu.show_all",
+            Action::Meet => "menu.meet",
+            Action::Breed => "menu.breed",
+            Action::FamilyTree => "menu.family_tree",
+            Action::Autopilot => "menu.autopilot",
+            Action::Log => "menu.session_log",
+            Action::Undo => "menu.undo",
+            Action::Actions => "menu.action_history",
+            Action::History => "menu.pet_history",
+            Action::Trends => "menu.trends",
+            Action::Skin => "menu.skin",
+            Action::Status => "repl.status",
+            Action::Menu => "repl.menu",
+            Action::Help => "repl.help",
+            Action::Quit => "menu.save_exit",
+        })
+    }
+
+    //The action for a word typed exactly, or its menu number
+    fn from_word(word: &str) -> Option<Self>
+    {
+        let word = word.to_lowercase();
+
+        match word.as_str()
+        {
+            "exit" => Some(Action::Quit),
+            _ => Self::ALL.into_iter().find(|action| action.name() == word || action.number().map(|number| number.to_string()) == Some(word.clone())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Command
+{
+    pub action: Action,
+    pub argument: Option<String>,
+}
+
+//Reads a line like "feed scooby snacks". An empty line is not a command.
+pub fn parse_command(line: &str) -> Result<Option<Command>, String>
+{
+    let line = line.trim();
+    if line.is_empty()
+    {
+        return Ok(None);
+    }
+
+    let (word, argument) = match line.split_once(char::is_whitespace)
+    {
+        Some((word, argument)) => (word, Some(argument.trim().to_string())),
+        None => (line, None),
+    };
+
+    let action = match Action::from_word(word)
+    {
+        Some(action) => action,
+        None =>
+        {
+            let names: Vec<String> = Action::ALL.iter().map(|action| action.name().to_string()).collect();
+
+            match fuzzy_find(word, &names, |name| name.to_string())
+            {
+                Ok(name) => Action::from_word(&name).expect("the name of an action"),
+                Err(suggestions) if suggestions.is_empty() => return Err(t!("repl.unknown_command", command = word)),
+                Err(suggestions) => return Err(t!("repl.did_you_mean", names = suggestions.join(", "))),
+            }
+        }
+    };
+
+    if argument.is_some() && action.argument().is_none()
+    {
+        return Err(t!("repl.no_argument", command = action.name()));
+    }
+
+    Ok(Some(Command { action, argument }))
+}
+
+//The number of single character edits needed to turn a word into the start of another
+fn distance(a: &str, b: &str) -> usize
+{
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate()
+    {
+        let mut current = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate()
+        {
+            let substitution = previous[j] + if a_char == *b_char {0} else {1};
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous.into_iter().min().unwrap_or_default()
+}
+
+//How well what was typed fits a name, lower is better: the same, the start of it, a part of
+//it or the start of it with a few typos. None if it doesn't fit at all.
+fn closeness(input: &str, name: &str) -> Option<usize>
+{
+    let name = name.to_lowercase();
+
+    if name == input
+    {
+        Some(0)
+    }
+    else if name.starts_with(input)
+    {
+        Some(1)
+    }
+    else if name.contains(input)
+    {
+        Some(2)
+    }
+    else
+    {
+        let edits = distance(input, &name);
+        (edits <= input.chars().count() / 3).then_some(3 + edits)
+    }
+}
+
+//The name that best fits what was typed, matching either the name or its translation.
+//When nothing fits, or several names fit equally well, the closest names are returned instead.
+pub fn fuzzy_find(input: &str, names: &[String], translated: impl Fn(&str) -> String) -> Result<String, Vec<String>>
+{
+    let input = input.trim().to_lowercase();
+    if input.is_empty()
+    {
+        return Err(Vec::new());
+    }
+
+    let scored: Vec<(usize, &String)> = names
+        .iter()
+        .filter_map(|name| [closeness(&input, name), closeness(&input, &translated(name))].into_iter().flatten().min().map(|score| (score, name)))
+        .collect();
+
+    let best = match scored.iter().map(|(score, _)| *score).min()
+    {
+        Some(best) => best,
+        None => return Err(Vec::new()),
+    };
+
+    let mut found: Vec<String> = scored.into_iter().filter(|(score, _)| *score == best).map(|(_, name)| name.clone()).collect();
+
+    if found.len() == 1
+    {
+        Ok(found.remove(0))
+    }
+    else
+    {
+        Err(found)
+    }
+}
+
+pub fn print_help(action: Option<Action>)
+{
+    let actions = match action
+    {
+        Some(action) => vec![action],
+        None =>
+        {
+            println!("{}", t!("repl.commands"));
+            Action::ALL.to_vec()
+        }
+    };
+
+    for action in actions
+    {
+        let usage = match action.argument()
+        {
+            Some(argument) => format!("{} [{}]", action.name(), argument),
+            None => action.name().to_string(),
+        };
+        let number = action.number().map(|number| format!("{})", number)).unwrap_or_default();
+
+        println!(" {:>3} {:<22} {}", number, usage, action.description());
+    }
+
+    if action.is_none()
+    {
+        println!("{}", t!("repl.help_hint"));
+    }
+}
+
+//Completes the command and then whatever the command takes, as it is shown to the player
+#[derive(Default)]
+pub struct ReplHelper
+{
+    foods: Vec<String>,
+    games: Vec<String>,
+    recipes: Vec<String>,
+}
+
+impl ReplHelper
+{
+    fn names(&self, action: Action) -> Vec<String>
+    {
+        match action
+        {
+            Action::Feed => self.foods.clone(),
+            Action::Play => self.games.clone(),
+            Action::Cook => self.recipes.clone(),
+            Action::Help => Action::ALL.iter().map(|action| action.name().to_string()).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Completer for ReplHelper
+{
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)>
+    {
+        let line = &line[..pos];
+
+        let (start, typed, names) = match line.split_once(char::is_whitespace)
+        {
+            None => (0, line, Action::ALL.iter().map(|action| action.name().to_string()).collect()),
+            Some((word, rest)) =>
+            {
+                let typed = rest.trim_start();
+                let names = Action::from_word(word).map(|action| self.names(action)).unwrap_or_default();
+                (pos - typed.len(), typed, names)
+            }
+        };
+
+        let typed = typed.to_lowercase();
+        let candidates = names
+            .into_iter()
+            .filter(|name| name.to_lowercase().starts_with(&typed))
+            .map(|name| Pair { display: name.clone(), replacement: name })
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper
+{
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+//Line editing with history, kept between games in HISTORY_FILE
+pub struct Repl
+{
+    editor: Editor<ReplHelper, DefaultHistory>,
+}
+
+impl Repl
+{
+    pub fn new() -> rustyline::Result<Self>
+    {
+        let mut editor = Editor::new()?;
+        editor.set_helper(Some(ReplHelper::default()));
+        let _ = editor.load_history(HISTORY_FILE); //There is none the first time
+
+        Ok(Self { editor })
+    }
+
+    //What tab completes depends on the pet being looked after
+    pub fn set_pet(&mut self, pet_kind: &PetKind, recipes: Vec<String>)
+    {
+        if let Some(helper) = self.editor.helper_mut()
+        {
+            helper.foods = get_food_for_pet(pet_kind).iter().map(|food| food_name(food)).collect();
+            helper.games = get_games(pet_kind).iter().map(|game| game_name(game)).collect();
+            helper.recipes = recipes.iter().map(|recipe| recipe_name(recipe)).collect();
+        }
+    }
+
+    //None once the input has ended, e.g. with Ctrl-D. Ctrl-C clears the line.
+    pub fn read_line(&mut self, prompt: &str) -> Option<String>
+    {
+        match self.editor.readline(prompt)
+        {
+            Ok(line) =>
+            {
+                if !line.trim().is_empty()
+                {
+                    let _ = self.editor.add_history_entry(line.as_str());
+                }
+                Some(line)
+            }
+            Err(ReadlineError::Interrupted) => Some(String::new()),
+            Err(_) => None,
+        }
+    }
+
+    pub fn save_history(&mut self)
+    {
+        if let Err(e) = self.editor.save_history(HISTORY_FILE)
+        {
+            println!("{}", t!("repl.history_not_saved", error = e));
+        }
+    }
+}
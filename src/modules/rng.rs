@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//Small seedable random number generator (SplitMix64). The same seed always gives the
+//same sequence, so anything random in the game can be replayed. The seed is kept next
+//to the current state, with how many numbers were drawn, so it can be reported in bug reports.
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+
+pub struct GameRng
+{
+    seed: u64,
+    state: u64,
+
+    #[serde(default)]
+    draws: u64,
+}
+
+impl GameRng
+{
+    pub fn new(seed: u64) -> Self
+    {
+        Self { seed, state: seed, draws: 0 }
+    }
+
+    //Seeded from the current time, for sessions without a --seed
+    pub fn from_time() -> Self
+    {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+        Self::new(seed)
+    }
+
+    pub fn seed(&self) -> u64
+    {
+        self.seed
+    }
+
+    //How far into the sequence of the seed the generator is
+    pub fn draws(&self) -> u64
+    {
+        self.draws
+    }
+
+    pub fn next_u64(&mut self) -> u64
+    {
+        self.draws += 1;
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    //Random number in 0..n
+    pub fn below(&mut self, n: u32) -> u32
+    {
+        if n == 0
+        {
+            return 0;
+        }
+
+        (self.next_u64() % n as u64) as u32
+    }
+
+    pub fn chance(&mut self, percent: u32) -> bool
+    {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T
+    {
+        &items[self.below(items.len() as u32) as usize]
+    }
+}
@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::sync::OnceLock;
+use std::time::Duration;
+use crate::t;
+
+//The numbers the game is balanced around: caps, warning thresholds, how fast the stats drop
+//and how long a tick lasts. They are read once from rules.json, or from the file named by
+//TAMAGOTCHI_RULES, so other rule sets can be played without rebuilding the game. Anything a
+//file leaves out keeps its classic value. Each pet is also played at a difficulty, which
+//adjusts these rules for it.
+
+pub const RULES_FILE: &str = "rules.json";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules
+{
+    pub lives: u8,
+    pub max_health: u8,
+    pub max_happiness: u8,
+    pub max_energy: u8,
+
+    //A new pet starts with these stats and gets them back when it loses a life
+    pub full_stats: u8,
+
+    //Stats lost every decay_every ticks, and as often while the pet is alone (in hours)
+    pub decay: u8,
+    pub decay_every: u8,
+
+    //Satiation from satiation_high up to satiation_high_max earns a warning, above
+    //satiation_dangerous it costs health
+    pub satiation_high: u8,
+    pub satiation_high_max: u8,
+    pub satiation_dangerous: u8,
+
+    //A pet with this much energy or less is tired and can be put to sleep
+    pub tired_energy: u8,
+
+    pub sleep_energy_gain: u8,
+    pub sleep_satiation_cost: u8,
+    pub wake_energy: u8, //The pet wakes up by itself once it has this much energy
+    pub grumpiness: u8, //Happiness lost when it is woken up early
+    pub sleep_cheers_up: bool, //A pet cheers up when it wakes up by itself
+
+    pub tick_seconds: u64,
+}
+
+impl Default for Rules
+{
+    fn default() -> Self
+    {
+        Self
+        {
+            lives: 1,
+            max_health: 10,
+            max_happiness: 15,
+            max_energy: 15,
+            full_stats: 10,
+            decay: 1,
+            decay_every: 1,
+            satiation_high: 11,
+            satiation_high_max: 14,
+            satiation_dangerous: 15,
+            tired_energy: 5,
+            sleep_energy_gain: 2,
+            sleep_satiation_cost: 1,
+            wake_energy: 10,
+            grumpiness: 2,
+            sleep_cheers_up: true,
+            tick_seconds: 30,
+        }
+    }
+}
+
+impl Rules
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(text: &str) -> Result<Self, String>
+    {
+        let rules: Rules = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    //Rules the game can't be played with, e.g. a pet that is tired even with full energy
+    pub fn validate(&self) -> Result<(), String>
+    {
+        let checks = [
+            (self.lives > 0, "lives must be at least 1"),
+            (self.max_health > 0, "max_health must be at least 1"),
+            (self.max_health <= 100 && self.max_happiness <= 100 && self.max_energy <= 100, "the caps can be at most 100"),
+            (self.full_stats > 0, "full_stats must be at least 1"),
+            (self.full_stats <= self.max_health, "full_stats can't be above max_health"),
+            (self.full_stats <= self.max_happiness && self.full_stats <= self.max_energy, "full_stats can't be above max_happiness or max_energy"),
+            (self.satiation_high > 0, "satiation_high must be at least 1"),
+            (self.satiation_high <= self.satiation_high_max, "satiation_high can't be above satiation_high_max"),
+            (self.satiation_high_max <= self.satiation_dangerous, "satiation_high_max can't be above satiation_dangerous"),
+            (self.satiation_dangerous <= 100, "satiation_dangerous can be at most 100"),
+            (self.tired_energy < self.full_stats, "tired_energy must be below full_stats"),
+            (self.wake_energy > self.tired_energy, "wake_energy must be above tired_energy"),
+            (self.wake_energy <= self.max_energy, "wake_energy can't be above max_energy"),
+            (self.decay_every > 0, "decay_every must be at least 1"),
+            (self.decay <= 50, "decay can be at most 50, hardcore pets lose twice as much"),
+            (self.grumpiness <= 100, "grumpiness can be at most 100"),
+            (self.sleep_energy_gain > 0, "sleep_energy_gain must be at least 1"),
+            (self.sleep_energy_gain <= 100 && self.sleep_satiation_cost <= 100, "sleep_energy_gain and sleep_satiation_cost can be at most 100"),
+            (self.tick_seconds > 0, "tick_seconds must be at least 1"),
+        ];
+
+        match checks.iter().find(|(ok, _)| !ok)
+        {
+            Some((_, problem)) => Err(problem.to_string()),
+            None => Ok(()),
+        }
+    }
+
+    //A stat at or below low_stat is shown as a warning and at or below very_low_stat as a
+    //danger. Both follow full_stats, 3 and 2 with the classic rules. A pet with at least
+    //high_stat happiness is happy, 8 with the classic rules.
+    pub fn low_stat(&self) -> u8
+    {
+        (self.full_stats as u16 * 3 / 10) as u8
+    }
+
+    pub fn very_low_stat(&self) -> u8
+    {
+        self.full_stats / 5
+    }
+
+    pub fn high_stat(&self) -> u8
+    {
+        (self.full_stats as u16 * 8 / 10) as u8
+    }
+
+    pub fn tick(&self) -> Duration
+    {
+        Duration::from_secs(self.tick_seconds)
+    }
+
+    //The rules for a pet played at the difficulty
+    pub fn for_difficulty(&self, difficulty: Difficulty) -> Self
+    {
+        match difficulty
+        {
+            Difficulty::Easy => Self { lives: self.lives.saturating_add(2), decay_every: self.decay_every.saturating_mul(2), ..*self },
+            Difficulty::Classic => *self,
+            Difficulty::Hardcore => Self
+            {
+                lives: 1,
+                decay: self.decay.saturating_mul(2),
+                sleep_energy_gain: (self.sleep_energy_gain / 2).max(1),
+                wake_energy: self.tired_energy + (self.wake_energy - self.tired_energy).div_ceil(2), //Halfway to rested
+                sleep_cheers_up: false,
+                ..*self
+            },
+        }
+    }
+
+    //Problems with the rules are reported and the classic rules are used instead
+    pub fn load() -> Self
+    {
+        let file = env::var("TAMAGOTCHI_RULES").unwrap_or(RULES_FILE.to_string());
+
+        let text = match fs::read_to_string(&file)
+        {
+            Ok(text) => text,
+            Err(_) if file == RULES_FILE => return Self::default(), //Playing with the classic rules
+            Err(e) =>
+            {
+                eprintln!("{}", t!("rules.read_failed", file = file, error = e));
+                return Self::default();
+            }
+        };
+
+        match Self::from_str(&text)
+        {
+            Ok(rules) => rules,
+            Err(e) =>
+            {
+                eprintln!("{}", t!("rules.invalid", file = file, error = e));
+                Self::default()
+            }
+        }
+    }
+}
+
+//Chosen when a pet is created and saved with it. Easy pets have two more lives and their stats
+//drop half as often, hardcore pets have a single life, their stats drop twice as fast and
+//sleeping gives back half the energy, ends halfway to rested and doesn't cheer them up.
+//Death is final at every difficulty.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Difficulty
+{
+    Easy,
+    #[default]
+    Classic,
+    Hardcore,
+}
+
+impl Difficulty
+{
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Classic, Difficulty::Hardcore];
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        match s.to_lowercase().as_str()
+        {
+            "easy" => Some(Difficulty::Easy),
+            "classic" => Some(Difficulty::Classic),
+            "hardcore" => Some(Difficulty::Hardcore),
+            _ => None,
+        }
+    }
+
+    //The name in the chosen language
+    pub fn name(&self) -> String
+    {
+        t!(match self
+        {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Classic => "difficulty.classic",
+            Difficulty::Hardcore => "difficulty.hardcore",
+        })
+    }
+
+    pub fn description(&self) -> String
+    {
+        t!(match self
+        {
+            Difficulty::Easy => "difficulty.easy_description",
+            Difficulty::Classic => "difficulty.classic_description",
+            Difficulty::Hardcore => "difficulty.hardcore_description",
+        })
+    }
+}
+
+static RULES: OnceLock<Rules> = OnceLock::new();
+
+//The rules can be chosen once, before the game starts. Returns false if it was too late.
+pub fn set_rules(rules: Rules) -> bool
+{
+    RULES.set(rules).is_ok() || self::rules() == rules
+}
+
+pub fn rules() -> Rules
+{
+    *RULES.get_or_init(Rules::load)
+}
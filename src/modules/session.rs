@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+use std::io;
+use crate::modules::pet::{is_dead, Pet};
+use crate::modules::autopilot::CarePolicy;
+use crate::modules::simulation::{Action, Simulation};
+use crate::modules::rng::GameRng;
+use crate::modules::clock::{GameClock, Home};
+use crate::modules::events::{initialize_events, EventTable};
+use crate::modules::history::{History, Snapshot};
+use crate::modules::journal::{EntryKind, Journal};
+use crate::t;
+
+//A play session with every pet that is currently being looked after.
+//One of them is active and receives the actions from the game menu.
+//The session owns the only random number generator of the game and the home the pets share.
+
+pub struct Session
+{
+    pets: Vec<Pet>,
+    active: usize,
+    autopilots: HashMap<String, Box<dyn CarePolicy + Send>>, //Policies by pet name
+    rng: GameRng,
+    home: Home,
+    events: EventTable,
+    log: Vec<String>, //Everything that happened by itself during the session
+    history: History,
+    journal: Journal, //Disabled unless the game turns it on
+    quiet: bool,      //Nothing is printed, e.g. while the full screen UI is drawn
+}
+
+impl Session
+{
+    //Starts a new random sequence
+    pub fn new(pet: Pet) -> Self
+    {
+        Self::with_rng(pet, GameRng::from_time())
+    }
+
+    pub fn with_seed(pet: Pet, seed: u64) -> Self
+    {
+        Self::with_rng(pet, GameRng::new(seed))
+    }
+
+    //Continues a random sequence, e.g. the one saved with the pet
+    pub fn with_rng(pet: Pet, rng: GameRng) -> Self
+    {
+        Self
+        {
+            pets: vec![pet],
+            active: 0,
+            autopilots: HashMap::new(),
+            rng,
+            home: Home::default(),
+            events: initialize_events(),
+            log: Vec::new(),
+            history: History::default(),
+            journal: Journal::disabled(),
+            quiet: false,
+        }
+    }
+
+    pub fn rng(&mut self) -> &mut GameRng
+    {
+        &mut self.rng
+    }
+
+    pub fn home(&self) -> &Home
+    {
+        &self.home
+    }
+
+    //The home saved by an earlier session, so the time of day carries on
+    pub fn set_home(&mut self, home: Home)
+    {
+        self.home = home;
+    }
+
+    //The lights are the same for every pet, so each of their journals gets the change
+    pub fn toggle_lights(&mut self)
+    {
+        self.home.lights_on = !self.home.lights_on;
+
+        if !self.quiet
+        {
+            println!("{}", t!("pet.lights", lights = self.home.lights()));
+        }
+
+        for pet in &self.pets
+        {
+            self.journal.write(pet, EntryKind::Lights, t!("entry.lights", lights = self.home.lights()));
+        }
+    }
+
+    //Every pet is left alone for the same hours, then the clock moves on
+    pub fn leave_all_alone(&mut self, hours: u8)
+    {
+        for pet in &mut self.pets
+        {
+            pet.leave_pet_alone(hours, &self.home);
+        }
+
+        self.home.clock.advance_hours(hours);
+    }
+
+    pub fn log(&self) -> &[String]
+    {
+        &self.log
+    }
+
+    pub fn history(&self) -> &History
+    {
+        &self.history
+    }
+
+    //Silences the session and every pet in it
+    pub fn set_quiet(&mut self, quiet: bool)
+    {
+        self.quiet = quiet;
+
+        for pet in &mut self.pets
+        {
+            pet.set_quiet(quiet);
+        }
+    }
+
+    pub fn set_journal(&mut self, journal: Journal)
+    {
+        self.journal = journal;
+    }
+
+    pub fn journal(&self) -> &Journal
+    {
+        &self.journal
+    }
+
+    //Writes an action to the journal of every pet it changed
+    pub fn journal_changes(&self, kind: EntryKind, description: &str, before: &[Pet])
+    {
+        for pet in &self.pets
+        {
+            if before.iter().find(|old| old.name() == pet.name()) != Some(pet)
+            {
+                self.journal.write(pet, kind, description.to_string());
+            }
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot
+    {
+        Snapshot
+        {
+            pets: self.pets.clone(),
+            rng: self.rng.clone(),
+            home: self.home.clone(),
+            log_length: self.log.len(),
+        }
+    }
+
+    //Remembers an action from the menu with the session as it was before it
+    pub fn record_command(&mut self, description: String, before: Snapshot)
+    {
+        let after = self.snapshot();
+        self.history.record(description, before, after);
+    }
+
+    //Goes back to the state before the last command and writes the undo in every pet's journal
+    pub fn undo(&mut self) -> bool
+    {
+        match self.history.undo(&self.pets)
+        {
+            Ok(command) =>
+            {
+                let before = command.before;
+
+                self.pets = before.pets;
+                self.rng = before.rng;
+                self.home = before.home;
+                self.log.truncate(before.log_length);
+
+                let undone = t!("undo.done", action = command.description);
+                for pet in &self.pets
+                {
+                    self.journal.write(pet, EntryKind::Undo, undone.clone());
+                }
+
+                println!("{}", undone);
+                true
+            }
+            Err(e) =>
+            {
+                println!("{}", e);
+                false
+            }
+        }
+    }
+
+    fn record(&mut self, line: String)
+    {
+        if !self.quiet
+        {
+            println!("{}", line);
+        }
+        self.log.push(format!("[{}] {}", self.home.clock, line));
+    }
+
+    pub fn pets(&self) -> &[Pet]
+    {
+        &self.pets
+    }
+
+    pub fn pets_mut(&mut self) -> &mut [Pet]
+    {
+        &mut self.pets
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.pets.is_empty()
+    }
+
+    pub fn active_index(&self) -> usize
+    {
+        self.active
+    }
+
+    pub fn active_pet(&mut self) -> &mut Pet
+    {
+        &mut self.pets[self.active]
+    }
+
+    //A pet can only be in the session once
+    pub fn add_pet(&mut self, mut pet: Pet) -> bool
+    {
+        if self.pets.iter().any(|p| p.name() == pet.name())
+        {
+            println!("{}", t!("session.already_in", name = pet.name()));
+            return false;
+        }
+
+        pet.set_quiet(self.quiet);
+        self.pets.push(pet);
+        self.active = self.pets.len() - 1;
+        true
+    }
+
+    pub fn switch_to(&mut self, name: &str) -> bool
+    {
+        match self.pets.iter().position(|p| p.name() == name)
+        {
+            Some(index) =>
+            {
+                self.active = index;
+                if !self.quiet
+                {
+                    println!("{}", t!("session.switched", name = name));
+                }
+                true
+            }
+            None =>
+            {
+                println!("{}", t!("session.no_such_pet", name = name));
+                false
+            }
+        }
+    }
+
+    //The active pet together with another pet of the session, for pets meeting each other
+    pub fn active_and(&mut self, name: &str) -> Option<(&mut Pet, &mut Pet)>
+    {
+        let other = self.pets.iter().position(|p| p.name() == name)?;
+
+        if other == self.active
+        {
+            return None;
+        }
+
+        let (low, high) = (self.active.min(other), self.active.max(other));
+        let (left, right) = self.pets.split_at_mut(high);
+        let (low_pet, high_pet) = (&mut left[low], &mut right[0]);
+
+        if self.active < other
+        {
+            Some((low_pet, high_pet))
+        }
+        else
+        {
+            Some((high_pet, low_pet))
+        }
+    }
+
+    //Turns the autopilot on for the active pet, or off when no policy is given
+    pub fn set_autopilot(&mut self, policy: Option<Box<dyn CarePolicy + Send>>)
+    {
+        let name = self.pets[self.active].name().to_string();
+
+        match policy
+        {
+            Some(policy) =>
+            {
+                println!("{}", t!("session.autopilot_on", policy = policy.name(), name = name));
+                self.autopilots.insert(name, policy);
+            }
+            None =>
+            {
+                println!("{}", t!("session.autopilot_off", name = name));
+                self.autopilots.remove(&name);
+            }
+        }
+    }
+
+    pub fn autopilot(&self, name: &str) -> Option<&str>
+    {
+        self.autopilots.get(name).map(|policy| policy.name())
+    }
+
+    pub fn run_autopilots(&mut self, simulation: &Simulation)
+    {
+        for index in 0..self.pets.len()
+        {
+            let pet = &mut self.pets[index];
+
+            if let Some(policy) = self.autopilots.get_mut(pet.name())
+            {
+                let action = policy.next_action(pet, &self.home, simulation);
+
+                if let Some(description) = action.describe(pet.name())
+                {
+                    let kind = match action
+                    {
+                        Action::Feed(_) => EntryKind::Feed,
+                        Action::Play(_) => EntryKind::Play,
+                        Action::Cook(_) => EntryKind::Cook,
+                        Action::Sleep | Action::Wait => EntryKind::Sleep,
+                    };
+
+                    let description = t!("session.autopilot_action", action = description);
+                    self.record(description.clone());
+                    simulation.apply(&mut self.pets[index], &action, &self.home);
+                    self.journal.write(&self.pets[index], kind, description);
+                }
+            }
+        }
+    }
+
+    //Time passes for every pet and random events may happen to them
+    pub fn tick_all(&mut self)
+    {
+        self.history.invalidate();
+        self.home.clock.advance_minutes(GameClock::MINUTES_PER_TICK);
+
+        for index in 0..self.pets.len()
+        {
+            self.pets[index].tick(&self.home);
+            self.journal.write(&self.pets[index], EntryKind::Tick, t!("entry.time_passed", time = self.home.clock));
+
+            if self.pets[index].is_egg()
+            {
+                continue;
+            }
+
+            if let Some(event) = self.events.roll(self.pets[index].kind(), &mut self.rng)
+            {
+                let line = event.apply(&mut self.pets[index]);
+                self.journal.write(&self.pets[index], EntryKind::Event, event.shown_name());
+                self.record(line);
+            }
+        }
+    }
+
+    //Runs the health checks on every pet after an action
+    pub fn check_all(&mut self)
+    {
+        for pet in &mut self.pets
+        {
+            self.journal.check(pet);
+        }
+    }
+
+    pub fn display_summary(&self)
+    {
+        println!("{}", t!("session.active_pets"));
+
+        for (index, pet) in self.pets.iter().enumerate()
+        {
+            let marker = if index == self.active {"*"} else {" "};
+
+            match self.autopilot(pet.name())
+            {
+                Some(policy) => println!("{} {}", marker, t!("session.with_autopilot", pet = pet.summary(), policy = policy)),
+                None => println!("{} {}", marker, pet.summary()),
+            }
+        }
+
+        println!();
+    }
+
+    //Dead pets are removed from the session (and their files by is_dead)
+    pub fn remove_dead(&mut self) -> Vec<String>
+    {
+        let mut removed = Vec::new();
+        let mut index = 0;
+
+        while index < self.pets.len()
+        {
+            if is_dead(&self.pets[index])
+            {
+                let pet = self.pets.remove(index);
+                self.journal.write_death(&pet);
+                removed.push(pet.name().to_string());
+
+                if self.active > index
+                {
+                    self.active -= 1;
+                }
+            }
+            else
+            {
+                index += 1;
+            }
+        }
+
+        if self.active >= self.pets.len()
+        {
+            self.active = self.pets.len().saturating_sub(1);
+        }
+
+        removed
+    }
+
+    //Saves every pet, the home and the random generator state once for all of them,
+    //returning the last error if any of them failed
+    pub fn save_all(&mut self) -> io::Result<()>
+    {
+        let mut result = self.home.save();
+
+        if let Err(e) = &result
+        {
+            println!("{}", t!("session.home_not_saved", error = e));
+        }
+
+        for pet in &mut self.pets
+        {
+            pet.set_rng(self.rng.clone());
+
+            match pet.save_pet_to_file()
+            {
+                Ok(()) => self.journal.write(pet, EntryKind::Save, t!("entry.saved")),
+                Err(e) =>
+                {
+                    println!("{}", t!("session.pet_not_saved", name = pet.name(), error = e));
+                    result = Err(e);
+                }
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,174 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use crate::modules::pet::{Pet, Warning};
+use crate::modules::food::FoodEffect;
+use crate::modules::games::GameEffect;
+use crate::modules::recipes::Recipe;
+use crate::modules::autopilot::CarePolicy;
+use crate::modules::clock::{GameClock, Home};
+use crate::modules::locale::{food_name, game_name, recipe_name};
+use crate::t;
+
+//Headless simulator for balancing the food and game numbers. A pet is run through a
+//sequence of actions, one per tick, without printing anything, and the outcome is
+//summed up in a report that can be compared between balance changes.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action
+{
+    Wait,
+    Feed(String),
+    Play(String),
+    Cook(String),
+    Sleep,
+}
+
+impl Action
+{
+    //Human readable description, None for waiting
+    pub fn describe(&self, pet_name: &str) -> Option<String>
+    {
+        match self
+        {
+            Action::Wait => None,
+            Action::Feed(food) => Some(t!("autopilot.feeding", name = pet_name, food = food_name(food))),
+            Action::Play(game) => Some(t!("autopilot.playing", game = game_name(game), name = pet_name)),
+            Action::Cook(recipe) => Some(t!("autopilot.cooking", recipe = recipe_name(recipe), name = pet_name)),
+            Action::Sleep => Some(t!("autopilot.sleeping", name = pet_name)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+
+pub struct SimulationReport
+{
+    pub ticks_run: u32,
+    pub time_to_death: Option<u32>, //Tick on which the pet lost its last life
+    pub lives_lost: u32,
+    pub failed_actions: u32,
+    pub average_health: f32,
+    pub average_happiness: f32,
+    pub average_energy: f32,
+    pub average_satiation: f32,
+    pub warnings: HashMap<Warning, u32>,
+}
+
+pub struct Simulation<'a>
+{
+    pub food_map: &'a HashMap<String, FoodEffect>,
+    pub games_map: &'a HashMap<String, GameEffect>,
+    pub recipes_map: &'a HashMap<String, Recipe>,
+}
+
+impl Simulation<'_>
+{
+    //Plays the script in order, one action per tick, and waits once it runs out
+    pub fn run_script(&self, pet: Pet, ticks: u32, script: &[Action]) -> SimulationReport
+    {
+        self.run(pet, ticks, |_, _, tick| script.get(tick as usize).cloned().unwrap_or(Action::Wait))
+    }
+
+    pub fn run_policy(&self, pet: Pet, ticks: u32, policy: &mut dyn CarePolicy) -> SimulationReport
+    {
+        self.run(pet, ticks, |pet, home, _| policy.next_action(pet, home, self))
+    }
+
+    //The policy picks the next action from the pet's state, its home and the current tick.
+    //The pet lives alone in a home of its own, starting in the morning.
+    pub fn run<F>(&self, mut pet: Pet, ticks: u32, mut policy: F) -> SimulationReport
+    where
+        F: FnMut(&Pet, &Home, u32) -> Action,
+    {
+        let mut report = SimulationReport::default();
+        let mut totals = [0u32; 4];
+        let mut home = Home::default();
+
+        pet.set_quiet(true);
+
+        for tick in 0..ticks
+        {
+            let action = policy(&pet, &home, tick);
+
+            if !self.apply(&mut pet, &action, &home)
+            {
+                report.failed_actions += 1;
+            }
+
+            home.clock.advance_minutes(GameClock::MINUTES_PER_TICK);
+            pet.tick(&home);
+
+            for warning in [pet.check_satiation(), pet.check_energy()].into_iter().flatten()
+            {
+                *report.warnings.entry(warning).or_insert(0) += 1;
+            }
+
+            if pet.is_health_zero()
+            {
+                report.lives_lost += 1;
+            }
+
+            report.ticks_run += 1;
+            totals[0] += pet.health() as u32;
+            totals[1] += pet.happiness() as u32;
+            totals[2] += pet.energy() as u32;
+            totals[3] += pet.satiation() as u32;
+
+            if pet.lives() == 0
+            {
+                report.time_to_death = Some(tick);
+                break;
+            }
+        }
+
+        if report.ticks_run > 0
+        {
+            let ticks_run = report.ticks_run as f32;
+            report.average_health = totals[0] as f32 / ticks_run;
+            report.average_happiness = totals[1] as f32 / ticks_run;
+            report.average_energy = totals[2] as f32 / ticks_run;
+            report.average_satiation = totals[3] as f32 / ticks_run;
+        }
+
+        report
+    }
+
+    //Applies an action the same way the game menu does, returns false if it failed
+    pub fn apply(&self, pet: &mut Pet, action: &Action, home: &Home) -> bool
+    {
+        if pet.is_egg()
+        {
+            return *action == Action::Wait;
+        }
+
+        match action
+        {
+            Action::Wait => true,
+            Action::Feed(food_name) =>
+            {
+                pet.wake_early();
+                pet.feed(food_name, self.food_map)
+            }
+            Action::Play(game_name) =>
+            {
+                pet.wake_early();
+                pet.play(game_name, self.games_map, home)
+            }
+            Action::Cook(recipe_name) =>
+            {
+                pet.wake_early();
+                pet.cook(recipe_name, self.recipes_map, self.food_map)
+            }
+            Action::Sleep =>
+            {
+                if pet.is_asleep() || !pet.is_tired()
+                {
+                    return false;
+                }
+
+                pet.sleep();
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+use crate::modules::pet::PetKind;
+use crate::modules::animation::{kind_file_name, parse_frames, shared_frames, Animation, KINDS};
+
+//Custom skins replace the built-in art. A skin is a directory in skins/ with a folder per
+//species and a file per animation, e.g. skins/neon/dog/idle.txt, each holding frames
+//separated by lines with three dashes. Anything a skin leaves out uses the built-in art.
+
+pub const SKINS_DIRECTORY: &str = "skins";
+pub const MAX_WIDTH: usize = 40;
+pub const MAX_HEIGHT: usize = 12;
+
+//Escapes copied from source code or JSON show up literally instead of as art
+const ESCAPES: [&str; 4] = ["\\n", "\\\"", "\\t", "\\u{"];
+
+pub struct Skin
+{
+    pub name: String,
+    frames: HashMap<(PetKind, Animation), Arc<[String]>>,
+}
+
+impl Skin
+{
+    //Reads a skin and checks every frame, returning all the problems found
+    pub fn load(directory: &str, name: &str) -> Result<Self, Vec<String>>
+    {
+        //A skin is a single folder in the skins directory and never a path out of it
+        if name.is_empty() || name.contains(['/', '\\']) || name.contains("..")
+        {
+            return Err(vec![format!("'{}' is not a skin name", name)]);
+        }
+
+        let skin_directory = Path::new(directory).join(name);
+        let mut frames = HashMap::new();
+        let mut errors = Vec::new();
+
+        let entries = match fs::read_dir(&skin_directory)
+        {
+            Ok(entries) => entries,
+            Err(e) => return Err(vec![format!("Could not read {}: {}", skin_directory.display(), e)]),
+        };
+
+        for entry in entries.flatten()
+        {
+            let folder = entry.file_name().to_string_lossy().to_string();
+
+            let kind = match KINDS.iter().find(|kind| kind_file_name(kind) == folder)
+            {
+                Some(kind) => kind,
+                None =>
+                {
+                    errors.push(format!("{}: not a species folder", folder));
+                    continue;
+                }
+            };
+
+            for file in fs::read_dir(entry.path()).into_iter().flatten().flatten()
+            {
+                let file_name = file.file_name().to_string_lossy().to_string();
+                let location = format!("{}/{}", folder, file_name);
+
+                let animation = match file_name.strip_suffix(".txt").and_then(Animation::from_str)
+                {
+                    Some(animation) => animation,
+                    None =>
+                    {
+                        errors.push(format!("{}: not an animation file (e.g. idle.txt)", location));
+                        continue;
+                    }
+                };
+
+                let text = match fs::read_to_string(file.path())
+                {
+                    Ok(text) => text,
+                    Err(e) =>
+                    {
+                        errors.push(format!("{}: {}", location, e));
+                        continue;
+                    }
+                };
+
+                let file_frames = parse_frames(&text);
+                if file_frames.is_empty()
+                {
+                    errors.push(format!("{}: has no frames", location));
+                }
+
+                for (index, frame) in file_frames.iter().enumerate()
+                {
+                    if let Err(e) = validate_frame(frame)
+                    {
+                        errors.push(format!("{} frame {}: {}", location, index + 1, e));
+                    }
+                }
+
+                frames.insert((kind.clone(), animation), Arc::from(file_frames));
+            }
+        }
+
+        if frames.is_empty() && errors.is_empty()
+        {
+            errors.push(format!("{} has no art", skin_directory.display()));
+        }
+
+        if !errors.is_empty()
+        {
+            return Err(errors);
+        }
+
+        Ok(Self { name: name.to_string(), frames })
+    }
+
+    pub fn frames(&self, pet_kind: &PetKind, animation: Animation) -> Option<&[String]>
+    {
+        self.frames.get(&(pet_kind.clone(), animation)).map(|frames| &frames[..])
+    }
+}
+
+//A frame has to fit in the stats screen and be plain printable text
+pub fn validate_frame(frame: &str) -> Result<(), String>
+{
+    let height = frame.lines().count();
+    if height > MAX_HEIGHT
+    {
+        return Err(format!("{} lines high, at most {} are allowed", height, MAX_HEIGHT));
+    }
+
+    for line in frame.lines()
+    {
+        let width = line.chars().count();
+        if width > MAX_WIDTH
+        {
+            return Err(format!("'{}' is {} characters wide, at most {} are allowed", line, width, MAX_WIDTH));
+        }
+
+        if line.chars().any(|c| c.is_control())
+        {
+            return Err(format!("'{}' has control characters (tabs or terminal codes)", line.escape_default()));
+        }
+
+        if let Some(escape) = ESCAPES.iter().find(|escape| line.contains(*escape))
+        {
+            return Err(format!("'{}' has an escaped {} - write the character itself", line, escape));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn skins_list() -> Vec<String>
+{
+    let mut skins: Vec<String> = fs::read_dir(SKINS_DIRECTORY)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+
+    skins.sort();
+    skins
+}
+
+//Skins are read once. A skin that fails to load is remembered as None and the
+//built-in art is used instead.
+fn loaded_skins() -> &'static Mutex<HashMap<String, Option<Skin>>>
+{
+    static SKINS: OnceLock<Mutex<HashMap<String, Option<Skin>>>> = OnceLock::new();
+    SKINS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+//Keeps a skin that was just loaded, e.g. after the player chose it
+pub fn remember(skin: Skin)
+{
+    loaded_skins().lock().unwrap().insert(skin.name.clone(), Some(skin));
+}
+
+//The frames of the skin, or the built-in ones when there is no skin or it lacks them.
+//They are shared, so redrawing a pet doesn't copy its art.
+pub fn skin_frames(skin: Option<&str>, pet_kind: &PetKind, animation: Animation) -> Arc<[String]>
+{
+    if let Some(name) = skin
+    {
+        let mut skins = loaded_skins().lock().unwrap();
+        let skin = skins.entry(name.to_string()).or_insert_with(|| Skin::load(SKINS_DIRECTORY, name).ok());
+
+        if let Some(skin_frames) = skin.as_ref().and_then(|skin| skin.frames.get(&(pet_kind.clone(), animation)))
+        {
+            return Arc::clone(skin_frames);
+        }
+    }
+
+    shared_frames(pet_kind, animation)
+}
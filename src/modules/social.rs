@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use crate::modules::pet::{Pet, PetKind};
+use crate::modules::games::{get_games, GameEffect};
+use crate::modules::clock::Home;
+use crate::modules::locale::game_name;
+use crate::t;
+
+//Pets that meet each other build up (or lose) friendship. Scores are kept for every pair
+//of pets in world/friendships.json and range from -10 (rivals) to 10 (best friends).
+
+pub enum Interaction
+{
+    PlayTogether(String),
+    Groom,
+    FightOverFood,
+}
+
+//How well two kinds get along, added to every interaction between them
+pub fn compatibility(a: &PetKind, b: &PetKind) -> i8
+{
+    use PetKind::*;
+
+    if a == b
+    {
+        return 2;
+    }
+
+    match (a, b)
+    {
+        (Cat, Parrot) | (Parrot, Cat) => -3,
+        (Cat, Dog) | (Dog, Cat) => -2,
+        (Cat, Rabbit) | (Rabbit, Cat) => -1,
+        (Cat, Squirrel) | (Squirrel, Cat) => -1,
+        (Dog, Squirrel) | (Squirrel, Dog) => -1,
+        (Monkey, Parrot) | (Parrot, Monkey) => 1,
+        (Rabbit, Squirrel) | (Squirrel, Rabbit) => 1,
+        _ => 0,
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+
+pub struct Friendships
+{
+    #[serde(with = "pairs")]
+    scores: HashMap<(String, String), i8>,
+}
+
+//JSON keys can only be strings, so the scores are saved as a list of [a, b, score]
+mod pairs
+{
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::HashMap;
+
+    pub fn serialize<S: Serializer>(scores: &HashMap<(String, String), i8>, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        let mut list: Vec<(&String, &String, &i8)> = scores.iter().map(|((a, b), score)| (a, b, score)).collect();
+        list.sort();
+        list.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<(String, String), i8>, D::Error>
+    {
+        let list: Vec<(String, String, i8)> = Vec::deserialize(deserializer)?;
+        Ok(list.into_iter().map(|(a, b, score)| ((a, b), score)).collect())
+    }
+}
+
+impl Friendships
+{
+    const FILE: &'static str = "world/friendships.json";
+
+    //The pair is stored under both names in alphabetical order, so A-B and B-A are the same
+    fn key(a: &str, b: &str) -> (String, String)
+    {
+        if a <= b
+        {
+            (a.to_string(), b.to_string())
+        }
+        else
+        {
+            (b.to_string(), a.to_string())
+        }
+    }
+
+    pub fn score(&self, a: &str, b: &str) -> i8
+    {
+        self.scores.get(&Self::key(a, b)).copied().unwrap_or(0)
+    }
+
+    pub fn change(&mut self, a: &str, b: &str, value: i8)
+    {
+        let score = self.scores.entry(Self::key(a, b)).or_insert(0);
+        *score = score.saturating_add(value).clamp(-10, 10);
+    }
+
+    pub fn level(&self, a: &str, b: &str) -> String
+    {
+        t!(match self.score(a, b)
+        {
+            i8::MIN..=-6 => "friendship.rivals",
+            -5..=-1 => "friendship.wary",
+            0..=4 => "friendship.acquaintances",
+            5..=7 => "friendship.friends",
+            _ => "friendship.best_friends",
+        })
+    }
+
+    pub fn load() -> io::Result<Self>
+    {
+        match fs::read_to_string(Self::FILE)
+        {
+            Ok(data) => Ok(serde_json::from_str(&data)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()>
+    {
+        fs::create_dir_all("world")?;
+        fs::write(Self::FILE, serde_json::to_string_pretty(&self)?)
+    }
+}
+
+//Games both pets know and that can be played together
+pub fn get_shared_games(a: &PetKind, b: &PetKind, games_map: &HashMap<String, GameEffect>) -> Vec<String>
+{
+    let games_b = get_games(b);
+
+    get_games(a)
+        .into_iter()
+        .filter(|game| games_b.contains(game))
+        .filter(|game| games_map.get(game).is_some_and(|effect| effect.multiplayer))
+        .collect()
+}
+
+pub fn interact(a: &mut Pet, b: &mut Pet, interaction: &Interaction, games_map: &HashMap<String, GameEffect>, home: &Home, friendships: &mut Friendships) -> bool
+{
+    let compat = compatibility(a.kind(), b.kind());
+
+    match interaction
+    {
+        Interaction::PlayTogether(game) =>
+        {
+            if !get_shared_games(a.kind(), b.kind(), games_map).contains(game)
+            {
+                println!("{}", t!("social.cannot_play_together", name = a.name(), other = b.name(), game = game_name(game)));
+                return false;
+            }
+
+            //Both pets pay the game's costs, so both must be up for it
+            let snapshot = (a.clone(), b.clone());
+            if !(a.play(game, games_map, home) && b.play(game, games_map, home))
+            {
+                (*a, *b) = snapshot;
+                return false;
+            }
+
+            a.set_happiness(compat);
+            b.set_happiness(compat);
+            friendships.change(a.name(), b.name(), 1 + compat);
+            println!("{}", t!("social.played_together", name = a.name(), other = b.name(), game = game_name(game), happiness = format!("{:+}", compat)));
+        }
+        Interaction::Groom =>
+        {
+            a.set_energy(-1);
+            b.set_energy(-1);
+            a.set_happiness(1 + compat);
+            b.set_happiness(1 + compat);
+            friendships.change(a.name(), b.name(), 2 + compat);
+            println!("{}", t!("social.groomed", name = a.name(), other = b.name(), happiness = format!("{:+}", 1 + compat)));
+        }
+        Interaction::FightOverFood =>
+        {
+            a.set_happiness(-2);
+            b.set_happiness(-2);
+
+            //The pet with less energy loses the fight
+            let loser = if a.energy() < b.energy() {&mut *a} else {&mut *b};
+            loser.set_health(-1);
+            let loser_name = loser.name().to_string();
+
+            println!("{}", t!("social.fought", name = a.name(), other = b.name(), loser = loser_name));
+
+            friendships.change(a.name(), b.name(), -3);
+        }
+    }
+
+    println!("{}", t!("social.now", name = a.name(), other = b.name(), level = friendships.level(a.name(), b.name())));
+    true
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use crate::modules::pet::{Mood, Pet, PetKind, Warning};
+use crate::modules::clock::Home;
+use crate::modules::rules::Difficulty;
+
+//Machine-readable status of a pet for status bars, editor plugins and dashboards.
+//The layout is documented in the README. Fields are only ever added, never renamed or
+//removed, and SCHEMA_VERSION goes up whenever something is added.
+
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(Serialize, Debug, PartialEq)]
+
+pub struct ClockStatus
+{
+    pub day: u32,
+    pub hour: u8,
+    pub minute: u8,
+    pub phase: String,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+
+pub struct ActionResult
+{
+    pub action: String,         //"new", "feed", "play" or "sleep"
+    pub target: Option<String>, //The food or game, if the action has one
+    pub success: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+
+pub struct PetStatus
+{
+    pub schema_version: u32,
+    pub name: String,
+    pub kind: PetKind,
+    pub difficulty: Difficulty, //Added in version 2
+    pub egg: bool,
+    pub asleep: bool,
+    pub age_minutes: u32,
+    pub clock: ClockStatus,
+    pub lights_on: bool,
+    pub lives: u8,
+    pub health: u8,
+    pub happiness: u8,
+    pub energy: u8,
+    pub satiation: u8,
+    pub mood: Mood,
+    pub warnings: Vec<Warning>,
+    pub last_action: Option<ActionResult>,
+}
+
+//The clock and the lights are the home's, which the pet shares with the others
+pub fn pet_status(pet: &Pet, home: &Home, last_action: Option<ActionResult>) -> PetStatus
+{
+    let clock = &home.clock;
+
+    PetStatus
+    {
+        schema_version: SCHEMA_VERSION,
+        name: pet.name().to_string(),
+        kind: pet.kind().clone(),
+        difficulty: pet.difficulty(),
+        egg: pet.is_egg(),
+        asleep: pet.is_asleep(),
+        age_minutes: pet.age(),
+        clock: ClockStatus
+        {
+            day: clock.day(),
+            hour: clock.hour(),
+            minute: clock.minute(),
+            phase: clock.phase().to_string().to_string(),
+        },
+        lights_on: home.lights_on,
+        lives: pet.lives(),
+        health: pet.health(),
+        happiness: pet.happiness(),
+        energy: pet.energy(),
+        satiation: pet.satiation(),
+        mood: pet.mood(),
+        warnings: pet.warnings(),
+        last_action,
+    }
+}
+
+//The status as one line of JSON
+pub fn status_json(pet: &Pet, home: &Home, last_action: Option<ActionResult>) -> String
+{
+    serde_json::to_string(&pet_status(pet, home, last_action)).expect("pet status is always valid JSON")
+}
@@ -0,0 +1,156 @@
+use crate::modules::journal::{EntryKind, JournalEntry};
+use crate::modules::display::{self, DisplayMode};
+use crate::t;
+
+//Sparklines of the stats over a period of time, drawn from the pet's journal. The period
+//is split into columns, each showing the last known value in it, starting from the value
+//the stat had when the period began. A second line marks the
+//columns where a warning fired (!) or a life was lost (X). Only the fancy display draws
+//the bars with Unicode blocks, the others stick to ASCII.
+
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const ASCII_BARS: [char; 8] = ['_', '.', ':', '-', '=', '+', '*', '#'];
+
+type Stat = fn(&JournalEntry) -> u8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Span
+{
+    Hour,
+    Day,
+    Week,
+}
+
+impl Span
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self>
+    {
+        match s.to_lowercase().as_str()
+        {
+            "hour" => Some(Span::Hour),
+            "day" => Some(Span::Day),
+            "week" => Some(Span::Week),
+            _ => None,
+        }
+    }
+
+    pub fn seconds(&self) -> u64
+    {
+        match self
+        {
+            Span::Hour => 3_600,
+            Span::Day => 86_400,
+            Span::Week => 604_800,
+        }
+    }
+
+    //The name in the chosen language, as it reads in the chart's title
+    pub fn name(&self) -> String
+    {
+        t!(match self
+        {
+            Span::Hour => "trends.hour",
+            Span::Day => "trends.day",
+            Span::Week => "trends.week",
+        })
+    }
+}
+
+//Which column of the chart a moment falls into, None if it is outside the span
+fn column(timestamp: u64, now: u64, span: Span, width: usize) -> Option<usize>
+{
+    let start = now.saturating_sub(span.seconds());
+
+    if width == 0 || timestamp < start || timestamp > now
+    {
+        return None;
+    }
+
+    let column = ((timestamp - start) as u128 * width as u128 / span.seconds() as u128) as usize;
+    Some(column.min(width - 1))
+}
+
+//One character per column, blank until the first known value
+pub fn sparkline(entries: &[JournalEntry], stat: Stat, now: u64, span: Span, width: usize, mode: DisplayMode) -> String
+{
+    let bars = if mode == DisplayMode::Fancy {BARS} else {ASCII_BARS};
+    let mut columns: Vec<Option<u8>> = vec![None; width];
+
+    //The newest value from before the span is where the line starts
+    let start = now.saturating_sub(span.seconds());
+    let carried_in = entries.iter().filter(|entry| entry.timestamp < start).max_by_key(|entry| entry.timestamp).map(stat);
+
+    for entry in entries
+    {
+        if let Some(column) = column(entry.timestamp, now, span, width)
+        {
+            columns[column] = Some(stat(entry));
+        }
+    }
+
+    //Stats can go above 10 (e.g. an overfed pet), so the scale grows with them
+    let top = columns.iter().flatten().chain(carried_in.iter()).copied().max().unwrap_or(10).max(10);
+
+    let mut line = String::new();
+    let mut last = carried_in;
+
+    for value in columns
+    {
+        last = value.or(last);
+
+        match last
+        {
+            Some(value) => line.push(bars[value as usize * (bars.len() - 1) / top as usize]),
+            None => line.push(' '),
+        }
+    }
+
+    line
+}
+
+pub fn markers(entries: &[JournalEntry], now: u64, span: Span, width: usize) -> String
+{
+    let mut line = vec![' '; width];
+
+    for entry in entries
+    {
+        let marker = match entry.kind
+        {
+            EntryKind::LifeLost | EntryKind::Death => 'X',
+            EntryKind::Warning => '!',
+            _ => continue,
+        };
+
+        if let Some(column) = column(entry.timestamp, now, span, width)
+        {
+            //A lost life is more important than a warning in the same column
+            if line[column] != 'X'
+            {
+                line[column] = marker;
+            }
+        }
+    }
+
+    line.into_iter().collect()
+}
+
+pub fn render_trends(entries: &[JournalEntry], now: u64, span: Span, width: usize, mode: DisplayMode) -> Vec<String>
+{
+    let stats: [(display::Stat, Stat); 4] = [
+        (display::Stat::Health, |entry| entry.health),
+        (display::Stat::Happiness, |entry| entry.happiness),
+        (display::Stat::Energy, |entry| entry.energy),
+        (display::Stat::Satiation, |entry| entry.satiation),
+    ];
+
+    let mut lines = Vec::new();
+
+    for (name, stat) in stats
+    {
+        lines.push(format!("{:<10}|{}|", name.name(), sparkline(entries, stat, now, span, width, mode)));
+    }
+
+    lines.push(format!("{:<10}|{}|", t!("trends.events"), markers(entries, now, span, width)));
+    lines
+}
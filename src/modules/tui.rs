@@ -0,0 +1,518 @@
+use std::io::{self, Stdout, Write};
+use std::time::{Duration, Instant};
+use crossterm::cursor;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{execute, queue};
+use crate::modules::pet::Pet;
+use crate::modules::food::get_food_for_pet;
+use crate::modules::games::get_games;
+use crate::modules::journal::EntryKind;
+use crate::modules::session::Session;
+use crate::modules::history::Snapshot;
+use crate::modules::simulation::Simulation;
+use crate::modules::clock::Home;
+use crate::modules::animation::{frame_at, Animation, ANIMATION_LENGTH};
+use crate::modules::display::{self, stat_line, DisplayMode, DisplaySettings, Stat};
+use crate::modules::rules::{rules, Rules};
+use crate::modules::locale::{food_name, game_name, kind_name, locale, translate_name};
+use crate::t;
+
+//Full screen terminal UI. The screen is split into four panes - the pet, its stats, the
+//actions and the event log - and is redrawn after every key and every tick. Ticks happen
+//in the same loop that reads the keys, so nothing is ever printed over the input.
+
+const REDRAW: Duration = Duration::from_millis(250); //For the countdown, or faster for animations
+const MAX_LOG: usize = 200;
+const LEAVE_HOURS: [u8; 8] = [1, 2, 3, 4, 6, 8, 12, 24];
+
+enum Choosing
+{
+    Food,
+    Game,
+    Hours,
+}
+
+struct Menu
+{
+    choosing: Choosing,
+    options: Vec<String>,
+    selected: usize,
+}
+
+//Puts the terminal back the way it was, even if drawing fails
+struct RawTerminal
+{
+    out: Stdout,
+}
+
+impl RawTerminal
+{
+    fn enter() -> io::Result<Self>
+    {
+        terminal::enable_raw_mode()?;
+        let mut out = io::stdout();
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self { out })
+    }
+}
+
+impl Drop for RawTerminal
+{
+    fn drop(&mut self)
+    {
+        let _ = execute!(self.out, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+pub struct Tui
+{
+    log: Vec<String>,
+    seen_log: usize, //How many lines of the session log are already in our log
+    menu: Option<Menu>,
+    last_tick: Instant,
+    fps: u32,
+    started: Instant,
+    action: Option<(Animation, Instant)>, //Animation of the last action and when it started
+}
+
+//Runs until the player quits or every pet is gone. Saving is left to the caller.
+pub fn run_tui(session: &mut Session, simulation: &Simulation, fps: u32) -> io::Result<()>
+{
+    let mut terminal = RawTerminal::enter()?;
+    session.set_quiet(true);
+
+    let mut tui = Tui
+    {
+        log: vec![t!("tui.welcome")],
+        seen_log: session.log().len(),
+        menu: None,
+        last_tick: Instant::now(),
+        fps,
+        started: Instant::now(),
+        action: None,
+    };
+
+    let result = tui.main_loop(&mut terminal.out, session, simulation);
+    session.set_quiet(false);
+    result
+}
+
+impl Tui
+{
+    fn main_loop(&mut self, out: &mut Stdout, session: &mut Session, simulation: &Simulation) -> io::Result<()>
+    {
+        queue!(out, terminal::Clear(ClearType::All))?;
+
+        let redraw = if self.fps == 0 {REDRAW} else {REDRAW.min(Duration::from_millis(1000 / self.fps as u64))};
+
+        while !session.is_empty()
+        {
+            self.draw(out, session)?;
+
+            if event::poll(redraw)?
+            {
+                match event::read()?
+                {
+                    Event::Key(key) if key.kind == KeyEventKind::Press && !self.handle_key(key, session, simulation) => break,
+                    Event::Resize(_, _) => queue!(out, terminal::Clear(ClearType::All))?,
+                    _ => {}
+                }
+            }
+
+            if self.last_tick.elapsed() >= rules().tick()
+            {
+                self.tick(session, simulation);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn message(&mut self, line: String)
+    {
+        self.log.push(line);
+
+        if self.log.len() > MAX_LOG
+        {
+            self.log.remove(0);
+        }
+    }
+
+    fn tick(&mut self, session: &mut Session, simulation: &Simulation)
+    {
+        self.last_tick = Instant::now();
+
+        session.run_autopilots(simulation);
+        session.tick_all();
+        self.collect_session_log(session);
+
+        for name in session.remove_dead()
+        {
+            self.message(t!("tui.died", name = name));
+        }
+    }
+
+    //Autopilot actions and world events end up in the session log
+    fn collect_session_log(&mut self, session: &Session)
+    {
+        let new_lines: Vec<String> = session.log()[self.seen_log..].to_vec();
+        self.seen_log = session.log().len();
+
+        for line in new_lines
+        {
+            self.message(line);
+        }
+    }
+
+    //Returns false when the player wants to quit
+    fn handle_key(&mut self, key: KeyEvent, session: &mut Session, simulation: &Simulation) -> bool
+    {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c')
+        {
+            return false;
+        }
+
+        if let Some(menu) = &mut self.menu
+        {
+            match key.code
+            {
+                KeyCode::Up => menu.selected = menu.selected.saturating_sub(1),
+                KeyCode::Down => menu.selected = (menu.selected + 1).min(menu.options.len().saturating_sub(1)),
+                KeyCode::Enter =>
+                {
+                    let menu = self.menu.take().unwrap();
+                    if let Some(option) = menu.options.get(menu.selected)
+                    {
+                        self.act(session, simulation, &menu.choosing, option);
+                    }
+                }
+                KeyCode::Esc => self.menu = None,
+                _ => {}
+            }
+
+            return true;
+        }
+
+        let pet = session.active_pet();
+
+        match key.code
+        {
+            KeyCode::Char('f') | KeyCode::Char('p') if pet.is_egg() =>
+            {
+                let line = t!("game.still_egg", name = pet.name());
+                self.message(line);
+            }
+            KeyCode::Char('f') =>
+            {
+                let options = get_food_for_pet(pet.kind()).into_iter().filter(|food| simulation.food_map.contains_key(food)).collect();
+                self.menu = Some(Menu { choosing: Choosing::Food, options, selected: 0 });
+            }
+            KeyCode::Char('p') =>
+            {
+                let options = get_games(pet.kind()).into_iter().filter(|game| simulation.games_map.contains_key(game)).collect();
+                self.menu = Some(Menu { choosing: Choosing::Game, options, selected: 0 });
+            }
+            KeyCode::Char('l') =>
+            {
+                let options = LEAVE_HOURS.iter().map(|hours| t!("tui.hours", hours = hours)).collect();
+                self.menu = Some(Menu { choosing: Choosing::Hours, options, selected: 0 });
+            }
+            KeyCode::Char('s') => self.put_to_sleep(session),
+            KeyCode::Tab =>
+            {
+                let next = (session.active_index() + 1) % session.pets().len();
+                let name = session.pets()[next].name().to_string();
+                session.switch_to(&name);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            _ => {}
+        }
+
+        true
+    }
+
+    fn put_to_sleep(&mut self, session: &mut Session)
+    {
+        let before = session.snapshot();
+        let pet = session.active_pet();
+        let name = pet.name().to_string();
+
+        if pet.is_egg()
+        {
+            self.message(t!("game.still_egg", name = name));
+        }
+        else if pet.is_asleep()
+        {
+            self.message(t!("sleep.already", name = name));
+        }
+        else if pet.is_tired()
+        {
+            pet.sleep();
+            self.message(t!("sleep.sleeping", name = name));
+            self.finish(session, EntryKind::Sleep, t!("command.sleep", name = name), before);
+        }
+        else
+        {
+            self.message(t!("sleep.not_tired", energy = pet.rules().tired_energy, name = name));
+        }
+    }
+
+    fn act(&mut self, session: &mut Session, simulation: &Simulation, choosing: &Choosing, option: &str)
+    {
+        let before = session.snapshot();
+        let home = session.home().clone();
+        let pet = session.active_pet();
+        let name = pet.name().to_string();
+
+        let (kind, description) = match choosing
+        {
+            Choosing::Food =>
+            {
+                pet.wake_early();
+                let line = if pet.feed(option, simulation.food_map) 
+                {
+                    self.action = Some((Animation::Eating, Instant::now()));
+                    t!("tui.ate", name = name, food = food_name(option))
+                } 
+                else 
+                {
+                    t!("tui.could_not_eat", name = name, food = food_name(option))
+                };
+                self.message(line);
+                (EntryKind::Feed, t!("command.feed", name = name))
+            }
+            Choosing::Game =>
+            {
+                pet.wake_early();
+                let line = if pet.play(option, simulation.games_map, &home) 
+                {
+                    self.action = Some((Animation::Playing, Instant::now()));
+                    t!("tui.played", game = game_name(option), name = name)
+                } 
+                else 
+                {
+                    t!("tui.could_not_play", name = name, game = game_name(option))
+                };
+                self.message(line);
+                (EntryKind::Play, t!("command.play", name = name))
+            }
+            Choosing::Hours =>
+            {
+                let hours: u8 = option.split_whitespace().next().and_then(|hours| hours.parse().ok()).unwrap_or(1);
+
+                session.leave_all_alone(hours);
+
+                self.message(t!("tui.gone", hours = hours));
+                (EntryKind::Leave, t!("command.leave", name = name))
+            }
+        };
+
+        self.finish(session, kind, description, before);
+    }
+
+    //The same bookkeeping as after every action in the game menu
+    fn finish(&mut self, session: &mut Session, kind: EntryKind, description: String, before: Snapshot)
+    {
+        session.journal_changes(kind, &description, &before.pets);
+        session.check_all();
+
+        for pet in session.pets()
+        {
+            for warning in pet.warnings()
+            {
+                self.message(format!("{}: {}", pet.name(), warning.name()));
+            }
+
+            if before.pets.iter().any(|old| old.name() == pet.name() && old.lives() > pet.lives())
+            {
+                self.message(t!("pet.lost_life", name = pet.name(), lives = pet.lives()));
+            }
+        }
+
+        if session.snapshot() != before
+        {
+            session.record_command(description, before);
+        }
+
+        for name in session.remove_dead()
+        {
+            self.message(t!("tui.died", name = name));
+        }
+    }
+
+    fn draw(&self, out: &mut Stdout, session: &mut Session) -> io::Result<()>
+    {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+
+        let left = width / 2;
+        let top = (height * 3 / 5).max(12).min(height);
+
+        let autopilot = session.autopilot(session.pets()[session.active_index()].name()).map(|policy| policy.to_string());
+        let position = format!("{}/{}", session.active_index() + 1, session.pets().len());
+        let next_tick = rules().tick().saturating_sub(self.last_tick.elapsed()).as_secs();
+        let pet = &session.pets()[session.active_index()];
+
+        draw_pane(out, 0, 0, left, top, &t!("tui.pet_title", name = pet.name(), kind = kind_name(pet.kind())), &self.art_lines(pet))?;
+        draw_pane(out, left, 0, width - left, top, &t!("tui.stats_title", position = position), &stat_lines(pet, session.home(), autopilot, next_tick))?;
+        draw_pane(out, 0, top, left, height - top, &t!("tui.actions"), &self.menu_lines(height - top))?;
+
+        let log_height = (height - top).saturating_sub(2);
+        let log_start = self.log.len().saturating_sub(log_height);
+        draw_pane(out, left, top, width - left, height - top, &t!("tui.events"), &self.log[log_start..])?;
+
+        out.flush()
+    }
+
+    //The animation of the last action for a moment, then how the pet feels
+    fn art_lines(&self, pet: &Pet) -> Vec<String>
+    {
+        if pet.is_egg()
+        {
+            return vec!["   ___".to_string(), "  /   \\".to_string(), " |     |".to_string(), "  \\___/".to_string(), String::new(), t!("tui.still_egg")];
+        }
+
+        let (animation, since) = match self.action
+        {
+            Some((animation, since)) if since.elapsed() < ANIMATION_LENGTH => (animation, since),
+            _ => (Animation::for_pet(pet), self.started),
+        };
+
+        frame_at(&pet.art(animation), since.elapsed(), self.fps).lines().map(|line| line.to_string()).collect()
+    }
+
+    fn menu_lines(&self, height: usize) -> Vec<String>
+    {
+        let menu = match &self.menu
+        {
+            Some(menu) => menu,
+            None =>
+            {
+                return vec![
+                    format!("[f] {}", t!("tui.feed")),
+                    format!("[p] {}", t!("tui.play")),
+                    format!("[s] {}", t!("tui.sleep")),
+                    format!("[l] {}", t!("tui.leave")),
+                    format!("[Tab] {}", t!("tui.next_pet")),
+                    format!("[q] {}", t!("tui.quit")),
+                ];
+            }
+        };
+
+        let title = t!(match menu.choosing
+        {
+            Choosing::Food => "tui.choose_food",
+            Choosing::Game => "tui.choose_game",
+            Choosing::Hours => "tui.choose_hours",
+        });
+
+        //Scrolls so that the selected option is always visible
+        let rows = height.saturating_sub(4).max(1);
+        let first = menu.selected.saturating_sub(rows - 1);
+
+        let mut lines = vec![title];
+        for (index, option) in menu.options.iter().enumerate().skip(first).take(rows)
+        {
+            let marker = if index == menu.selected {">"} else {" "};
+            let option = match menu.choosing
+            {
+                Choosing::Food => food_name(option),
+                Choosing::Game => game_name(option),
+                Choosing::Hours => option.clone(),
+            };
+            lines.push(format!("{} {}", marker, option));
+        }
+        lines.push(t!("tui.menu_help"));
+        lines
+    }
+}
+
+fn stat_lines(pet: &Pet, home: &Home, autopilot: Option<String>, next_tick: u64) -> Vec<String>
+{
+    let clock = &home.clock;
+    let settings = display::settings();
+    let rules = pet.rules();
+
+    let phase = clock.phase().name();
+    let mood = translate_name(locale(), "mood", &format!("{:?}", pet.mood()));
+
+    let mut lines = vec![
+        t!("tui.time", day = clock.day() + 1, time = format!("{:02}:{:02}", clock.hour(), clock.minute()), phase = phase, lights = home.lights()),
+        format!("{}{}", t!("tui.mood", mood = mood), autopilot.map(|policy| format!("  [{}]", t!("tui.autopilot", policy = policy))).unwrap_or_default()),
+        t!("stats.difficulty", difficulty = pet.difficulty().name()),
+        String::new(),
+    ];
+
+    let stats = [(Stat::Health, pet.health()), (Stat::Happiness, pet.happiness()), (Stat::Energy, pet.energy()), (Stat::Satiation, pet.satiation())];
+
+    if settings.mode == DisplayMode::Fancy
+    {
+        lines.push(format!("{:<10} {}", Stat::Lives.name(), "♥ ".repeat(pet.lives() as usize)));
+        lines.extend(stats.iter().map(|(stat, value)| format!("{:<10} {}", stat.name(), gauge(*value, &rules))));
+    }
+    else
+    {
+        //Panes are cut to fit by counting characters, so there is no colour in here
+        let settings = DisplaySettings { colour: false, ..settings };
+        lines.push(stat_line(Stat::Lives, pet.lives(), &rules, &settings));
+        lines.extend(stats.iter().map(|(stat, value)| stat_line(*stat, *value, &rules, &settings)));
+    }
+    lines.push(String::new());
+
+    let warnings = pet.warnings();
+    if !warnings.is_empty()
+    {
+        let warnings: Vec<String> = warnings.into_iter().map(|warning| warning.name()).collect();
+        lines.push(t!("tui.warnings", warnings = warnings.join(", ")));
+    }
+
+    lines.push(t!("tui.next_tick", seconds = next_tick));
+    lines
+}
+
+//How wide the stat bars are drawn, whatever the stats a pet starts with
+const GAUGE_CELLS: usize = 10;
+
+//A bar for the stats a pet starts with, with a + for every cell's worth above them
+fn gauge(value: u8, rules: &Rules) -> String
+{
+    let cells = |points: u8| (points as usize * GAUGE_CELLS).div_ceil(rules.full_stats as usize);
+    let filled = cells(value.min(rules.full_stats));
+    format!("[{}{}]{} {}", "█".repeat(filled), "░".repeat(GAUGE_CELLS - filled), "+".repeat(cells(value.saturating_sub(rules.full_stats))), value)
+}
+
+//A box with a title, the lines cut to fit and the rest of the box cleared
+fn draw_pane(out: &mut Stdout, x: usize, y: usize, width: usize, height: usize, title: &str, lines: &[String]) -> io::Result<()>
+{
+    if width < 4 || height < 2
+    {
+        return Ok(());
+    }
+
+    //Corners, the horizontal and the vertical line
+    let (top_left, top_right, bottom_left, bottom_right, horizontal, vertical) = match display::settings().mode
+    {
+        DisplayMode::Fancy => ("┌", "┐", "└", "┘", "─", "│"),
+        _ => ("+", "+", "+", "+", "-", "|"),
+    };
+
+    let inner = width - 2;
+    let title: String = format!(" {} ", title).chars().take(inner).collect();
+    let top = format!("{}{}{}{}", top_left, title, horizontal.repeat(inner - title.chars().count()), top_right);
+
+    queue!(out, cursor::MoveTo(x as u16, y as u16), Print(top))?;
+
+    for row in 0..height - 2
+    {
+        let line = lines.get(row).map(|line| line.as_str()).unwrap_or("");
+        let mut text: String = line.chars().take(inner).collect();
+        text.push_str(&" ".repeat(inner - text.chars().count()));
+
+        queue!(out, cursor::MoveTo(x as u16, (y + 1 + row) as u16), Print(format!("{}{}{}", vertical, text, vertical)))?;
+    }
+
+    queue!(out, cursor::MoveTo(x as u16, (y + height - 1) as u16), Print(format!("{}{}{}", bottom_left, horizontal.repeat(inner), bottom_right)))
+}
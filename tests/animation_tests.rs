@@ -0,0 +1,52 @@
+use std::time::Duration;
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::animation::{frame_at, frames, parse_animations, Animation};
+
+
+#[test]
+fn test_every_kind_has_every_animation() 
+{
+    for kind in [PetKind::Axalotl, PetKind::Cat, PetKind::Dog, PetKind::Monkey, PetKind::Parrot, PetKind::Rabbit, PetKind::Squirrel] 
+    {
+        for animation in Animation::ALL 
+        {
+            assert!(frames(&kind, animation).len() >= 2, "{:?} {:?} is not animated", kind, animation);
+        }
+
+        assert_eq!(kind.ascii_art(), frames(&kind, Animation::Idle)[0]);
+    }
+}
+
+#[test]
+fn test_parsing_animation_files() 
+{
+    let mut text = String::from("# Comment\n");
+    for animation in Animation::ALL 
+    {
+        text.push_str(&format!("[{}]\n o\n---\n O\n", animation.name()));
+    }
+
+    let animations = parse_animations(&text).unwrap();
+    assert_eq!(animations[&Animation::Sad], vec![" o".to_string(), " O".to_string()]);
+
+    assert!(parse_animations("[idle]\n o\n").is_err()); // Other animations are missing
+    assert!(parse_animations(&format!("{}[dancing]\n o\n", text)).is_err());
+    assert!(parse_animations(&format!("{}[idle]\n o\n", text)).is_err()); // Defined twice
+    assert!(parse_animations(&format!(" o\n{}", text)).is_err()); // Art before any animation
+}
+
+#[test]
+fn test_frames_loop_at_the_frame_rate() 
+{
+    let frames = vec!["a".to_string(), "b".to_string()];
+
+    assert_eq!(frame_at(&frames, Duration::from_millis(0), 4), "a");
+    assert_eq!(frame_at(&frames, Duration::from_millis(250), 4), "b");
+    assert_eq!(frame_at(&frames, Duration::from_millis(500), 4), "a");
+    assert_eq!(frame_at(&frames, Duration::from_secs(3), 0), "a"); // No animation
+
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    assert_eq!(Animation::for_pet(&pet), Animation::Idle);
+    pet.set_satiation(-8);
+    assert_eq!(Animation::for_pet(&pet), Animation::Sad);
+}
@@ -0,0 +1,53 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::games::initialize_games;
+use tamagotchi::modules::recipes::initialize_recipes;
+use tamagotchi::modules::simulation::{Action, Simulation};
+use tamagotchi::modules::autopilot::{policy_by_name, CarePolicy, GreedyPolicy, NeglectfulPolicy};
+
+
+#[test]
+fn test_greedy_policy_fixes_the_most_urgent_need() 
+{
+    let food_map = initialize_food();
+    let games_map = initialize_games();
+    let recipes_map = initialize_recipes();
+    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+    let mut policy = GreedyPolicy;
+
+    let mut pet = Pet::new("Auto".to_string(), PetKind::Rabbit);
+    let home = Home::default();
+    assert_eq!(policy.next_action(&pet, &home, &simulation), Action::Wait);
+
+    pet.set_satiation(-5);
+    assert_eq!(policy.next_action(&pet, &home, &simulation), Action::Feed("Carrot".to_string())); // Most filling healthy food
+
+    pet.set_satiation(5);
+    pet.set_energy(-6);
+    assert_eq!(policy.next_action(&pet, &home, &simulation), Action::Sleep);
+}
+
+#[test]
+fn test_greedy_outlives_neglectful() 
+{
+    let food_map = initialize_food();
+    let games_map = initialize_games();
+    let recipes_map = initialize_recipes();
+    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
+    let greedy = simulation.run_policy(Pet::new("Auto".to_string(), PetKind::Cat), 500, &mut GreedyPolicy);
+    let neglectful = simulation.run_policy(Pet::new("Auto".to_string(), PetKind::Cat), 500, &mut NeglectfulPolicy::new(10));
+
+    assert_eq!(greedy.time_to_death, None);
+    assert!(neglectful.time_to_death.is_some());
+    assert!(greedy.average_happiness > neglectful.average_happiness);
+}
+
+#[test]
+fn test_policies_by_name() 
+{
+    assert_eq!(policy_by_name("Greedy").unwrap().name(), "greedy");
+    assert_eq!(policy_by_name("neglectful").unwrap().name(), "neglectful");
+    assert!(policy_by_name("lazy").is_none());
+}
@@ -0,0 +1,66 @@
+use tamagotchi::modules::pet::{pets_in, Pet, PetKind};
+use tamagotchi::modules::rules::Difficulty;
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::journal::Journal;
+use tamagotchi::modules::cli::{act, format_list, parse_args, OutputFormat, Subcommand, EXIT_REFUSED};
+
+
+fn args(line: &str) -> Vec<String> 
+{
+    line.split_whitespace().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_no_arguments_start_the_game() 
+{
+    assert_eq!(parse_args(&[]), Ok(None));
+}
+
+#[test]
+fn test_parsing_subcommands() 
+{
+    assert_eq!(parse_args(&args("new Rex --kind dog")), Ok(Some(Subcommand::New { name: "Rex".to_string(), kind: PetKind::Dog, difficulty: Difficulty::Classic })));
+    assert_eq!(parse_args(&args("new Rex --kind dog --difficulty hardcore")), Ok(Some(Subcommand::New { name: "Rex".to_string(), kind: PetKind::Dog, difficulty: Difficulty::Hardcore })));
+    assert_eq!(parse_args(&args("feed Rex Bone")), Ok(Some(Subcommand::Feed { name: "Rex".to_string(), food: "Bone".to_string() })));
+    assert_eq!(parse_args(&args("play Tom Chase the laser")), Ok(Some(Subcommand::Play { name: "Tom".to_string(), game: "Chase the laser".to_string() })));
+    assert_eq!(parse_args(&args("status Rex")), Ok(Some(Subcommand::Status { name: "Rex".to_string() })));
+    assert_eq!(parse_args(&args("list")), Ok(Some(Subcommand::List)));
+}
+
+#[test]
+fn test_invalid_subcommands() 
+{
+    assert!(parse_args(&args("dance Rex")).is_err());
+    assert!(parse_args(&args("new Rex")).is_err()); // No kind
+    assert!(parse_args(&args("new Rex --kind dragon")).is_err());
+    assert!(parse_args(&args("new --kind dog Rex")).is_err()); // The name is an option
+    assert!(parse_args(&args("new Rex --kind dog --difficulty nightmare")).is_err());
+    assert!(parse_args(&args("feed Rex")).is_err()); // No food
+    assert!(parse_args(&args("sleep")).is_err());
+}
+
+#[test]
+fn test_actions_for_another_kind_are_refused() 
+{
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    pet.set_quiet(true);
+    let (home, journal) = (Home::default(), Journal::disabled());
+
+    assert_eq!(act(&mut pet, "feed", Some("Whiskas"), &home, &journal), EXIT_REFUSED);
+    assert_eq!(act(&mut pet, "play", Some("Chase the laser"), &home, &journal), EXIT_REFUSED);
+    assert_eq!(pet.satiation(), pet.rules().full_stats); // Nothing was eaten
+}
+
+#[test]
+fn test_list_of_no_pets_is_valid_json() 
+{
+    let directory = std::env::temp_dir().join("tamagotchi_pets_empty");
+    std::fs::create_dir_all(&directory).unwrap();
+    let names = pets_in(directory.to_str().unwrap()).unwrap();
+    std::fs::remove_dir(&directory).unwrap();
+
+    assert!(names.is_empty());
+    let parsed: Vec<String> = serde_json::from_str(&format_list(&names, OutputFormat::Json)).unwrap();
+    assert!(parsed.is_empty());
+    assert_eq!(format_list(&names, OutputFormat::Text), "");
+}
@@ -0,0 +1,73 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::{GameClock, Home, Phase};
+use tamagotchi::modules::session::Session;
+use tamagotchi::modules::games::initialize_games;
+
+
+#[test]
+fn test_clock_phases() 
+{
+    let mut clock = GameClock::default();
+
+    assert_eq!(clock.hour(), 8);
+    assert_eq!(clock.phase(), Phase::Day);
+
+    clock.advance_hours(13); // 21:00
+    assert_eq!(clock.phase(), Phase::Night);
+
+    clock.advance_hours(10); // 07:00 the next day
+    assert_eq!(clock.day(), 1);
+    assert_eq!(clock.phase(), Phase::Day);
+}
+
+#[test]
+fn test_night_decay_for_awake_pet() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Cat);
+    let mut home = Home { clock: GameClock::at(0, 23, 30), lights_on: true };
+
+    pet.tick(&home);
+
+    assert_eq!(pet.energy(), 8);    // -1 tick, -1 for staying up at night
+    assert_eq!(pet.happiness(), 8); // -1 tick, -1 for the lights being on
+
+    home.lights_on = false;
+    pet.tick(&home);
+
+    assert_eq!(pet.happiness(), 7); // Only the tick decay with the lights off
+}
+
+#[test]
+fn test_pets_share_the_clock_and_lights() 
+{
+    //No random event happens on the first tick with this seed
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    session.add_pet(Pet::new("Tom".to_string(), PetKind::Cat));
+    session.set_home(Home { clock: GameClock::at(0, 23, 0), lights_on: true });
+
+    session.toggle_lights();
+    session.tick_all();
+
+    assert_eq!(session.home().clock.minute(), 30); // Moved on once, not once per pet
+    assert!(!session.home().lights_on);
+
+    for pet in session.pets() 
+    {
+        assert_eq!(pet.happiness(), 9); // Only the tick decay, the lights are off for both
+        assert_eq!(pet.energy(), 8);    // -1 for staying up at night
+    }
+}
+
+#[test]
+fn test_daytime_only_games() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Parrot);
+    let games_map = initialize_games();
+
+    let night = Home { clock: GameClock::at(0, 22, 0), lights_on: true };
+    assert!(!pet.play("Flying races", &games_map, &night));
+    assert!(pet.play("Hide and seek", &games_map, &night));
+
+    let day = Home { clock: GameClock::at(1, 12, 0), lights_on: true };
+    assert!(pet.play("Flying races", &games_map, &day));
+}
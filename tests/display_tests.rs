@@ -0,0 +1,86 @@
+use tamagotchi::modules::display::{stat_line, DisplayMode, DisplaySettings, Level, Stat, Theme};
+use tamagotchi::modules::locale::{set_locale, Locale};
+use tamagotchi::modules::rules::{Difficulty, Rules};
+
+
+fn no_variables(_: &str) -> Option<String>
+{
+    None
+}
+
+#[test]
+fn test_settings_from_file_and_environment()
+{
+    let file = r#"{"display": "plain", "theme": "colourblind"}"#;
+
+    let settings = DisplaySettings::from_sources(Some(file), no_variables).unwrap();
+    assert_eq!(settings, DisplaySettings { mode: DisplayMode::Plain, theme: Theme::Colourblind, colour: true });
+
+    //The environment wins over the file
+    let settings = DisplaySettings::from_sources(Some(file), |name| (name == "TAMAGOTCHI_DISPLAY").then(|| "fancy".to_string())).unwrap();
+    assert_eq!(settings.mode, DisplayMode::Fancy);
+
+    assert!(DisplaySettings::from_sources(Some(r#"{"display": "sparkly"}"#), no_variables).is_err());
+    assert!(DisplaySettings::from_sources(None, |_| Some("sparkly".to_string())).is_err());
+}
+
+#[test]
+fn test_no_color_turns_colour_off()
+{
+    let settings = DisplaySettings::from_sources(None, |name| (name == "NO_COLOR").then(|| "1".to_string())).unwrap();
+    assert!(!settings.colour);
+
+    //An empty NO_COLOR doesn't count
+    let settings = DisplaySettings::from_sources(None, |name| (name == "NO_COLOR").then(String::new)).unwrap();
+    assert!(settings.colour);
+
+    let settings = DisplaySettings::from_sources(Some(r#"{"display": "screen-reader"}"#), no_variables).unwrap();
+    assert!(!settings.colour);
+}
+
+#[test]
+fn test_warning_levels()
+{
+    let rules = Rules::default();
+    assert_eq!(Stat::Health.level(7, &rules), Level::Good);
+    assert_eq!(Stat::Energy.level(5, &rules), Level::Warning);
+    assert_eq!(Stat::Happiness.level(2, &rules), Level::Danger);
+    assert_eq!(Stat::Satiation.level(8, &rules), Level::Good);
+    assert_eq!(Stat::Satiation.level(12, &rules), Level::Warning);
+    assert_eq!(Stat::Satiation.level(16, &rules), Level::Danger);
+    assert_eq!(Stat::Satiation.level(0, &rules), Level::Danger);
+}
+
+#[test]
+fn test_stat_lines()
+{
+    assert!(set_locale(Locale::English));
+    let rules = Rules::default();
+
+    let plain = DisplaySettings { mode: DisplayMode::Plain, theme: Theme::Classic, colour: false };
+    assert_eq!(stat_line(Stat::Health, 7, &rules, &plain), "Health:    [#######---] 7/10");
+    assert_eq!(stat_line(Stat::Satiation, 12, &rules, &plain), "Satiation: [############---] 12/15");
+
+    let reader = DisplaySettings { mode: DisplayMode::ScreenReader, ..plain };
+    assert_eq!(stat_line(Stat::Health, 7, &rules, &reader), "Health 7 of 10");
+    assert_eq!(stat_line(Stat::Energy, 4, &rules, &reader), "Energy 4 of 15, low");
+    assert_eq!(stat_line(Stat::Satiation, 12, &rules, &reader), "Satiation 12 of 15, overfed");
+    assert_eq!(stat_line(Stat::Happiness, 12, &rules, &reader), "Happiness 12 of 15");
+
+    //Colour wraps the line in escape codes
+    let coloured = DisplaySettings { colour: true, ..plain };
+    assert!(stat_line(Stat::Health, 1, &rules, &coloured).starts_with('\u{1b}'));
+}
+
+#[test]
+fn test_stats_are_shown_out_of_the_pet_rules()
+{
+    assert!(set_locale(Locale::English));
+    let reader = DisplaySettings { mode: DisplayMode::ScreenReader, theme: Theme::Classic, colour: false };
+
+    //An easy pet has two more lives, so it is drawn out of those
+    let easy = Rules::default().for_difficulty(Difficulty::Easy);
+    assert_eq!(Stat::Lives.scale(&easy), 3);
+    assert_eq!(stat_line(Stat::Lives, 3, &easy, &reader), "Lives 3");
+    assert_eq!(Stat::Lives.scale(&Rules::default()), 1);
+}
@@ -0,0 +1,50 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::events::initialize_events;
+use tamagotchi::modules::rng::GameRng;
+use tamagotchi::modules::session::Session;
+
+
+#[test]
+fn test_species_weights() 
+{
+    let events = initialize_events();
+    let mut rng = GameRng::new(5);
+
+    for _ in 0..200 
+    {
+        let event = events.pick(&PetKind::Parrot, &mut rng).unwrap();
+        assert_ne!(event.name, "Butterfly"); // Parrots never chase butterflies
+    }
+}
+
+#[test]
+fn test_species_effects() 
+{
+    let events = initialize_events();
+    let storm = events.events().iter().find(|event| event.name == "Thunderstorm").unwrap();
+
+    let mut dog = Pet::new("Rex".to_string(), PetKind::Dog);
+    let mut cat = Pet::new("Tom".to_string(), PetKind::Cat);
+
+    let line = storm.apply(&mut dog);
+    storm.apply(&mut cat);
+
+    assert_eq!(dog.happiness(), 7); // Dogs are more afraid of storms
+    assert_eq!(dog.energy(), 9);
+    assert_eq!(cat.happiness(), 8);
+    assert!(line.contains("Rex got scared by a thunderstorm!"));
+}
+
+#[test]
+fn test_events_show_up_in_the_session_log() 
+{
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 11);
+
+    for _ in 0..40 
+    {
+        session.tick_all();
+    }
+
+    assert!(!session.log().is_empty());
+    assert!(session.log()[0].starts_with("[Day 1"));
+}
@@ -0,0 +1,94 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::genetics::{breed, FamilyTree, Trait};
+use tamagotchi::modules::games::initialize_games;
+use tamagotchi::modules::rng::GameRng;
+
+
+fn adult(name: &str, kind: PetKind) -> Pet 
+{
+    let mut pet = Pet::new(name.to_string(), kind);
+    pet.leave_pet_alone(72, &Home::default()); // Three days pass
+    pet
+}
+
+#[test]
+fn test_rng_is_deterministic() 
+{
+    let mut a = GameRng::new(42);
+    let mut b = GameRng::new(42);
+
+    for _ in 0..10 
+    {
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+
+    assert!(a.below(6) < 6);
+}
+
+#[test]
+fn test_only_adults_of_the_same_kind_breed() 
+{
+    let young = Pet::new("Young".to_string(), PetKind::Dog);
+    let rex = adult("Rex", PetKind::Dog);
+    let tom = adult("Tom", PetKind::Cat);
+
+    assert!(young.age() < rex.age());
+    assert!(breed(&rex, &young, "Puppy".to_string(), 1).is_err());
+    assert!(breed(&rex, &tom, "Puppy".to_string(), 1).is_err());
+    assert!(breed(&rex, &rex, "Puppy".to_string(), 1).is_err());
+}
+
+#[test]
+fn test_breeding_is_deterministic_with_a_seed() 
+{
+    let rex = adult("Rex", PetKind::Dog);
+    let lady = adult("Lady", PetKind::Dog);
+
+    let first = breed(&rex, &lady, "Puppy".to_string(), 7).unwrap();
+    let second = breed(&rex, &lady, "Puppy".to_string(), 7).unwrap();
+
+    assert_eq!(first.genes(), second.genes());
+    assert_eq!(first.energy(), second.energy());
+    assert_eq!(first.kind(), &PetKind::Dog);
+}
+
+#[test]
+fn test_egg_hatches_and_joins_the_family_tree() 
+{
+    let rex = adult("Rex", PetKind::Dog);
+    let lady = adult("Lady", PetKind::Dog);
+    let mut puppy = breed(&rex, &lady, "Puppy".to_string(), 3).unwrap();
+
+    assert!(puppy.is_egg());
+
+    let satiation = puppy.satiation();
+    for _ in 0..12 
+    {
+        puppy.tick(&Home::default()); // Six in-game hours
+    }
+
+    assert!(!puppy.is_egg());
+    assert_eq!(puppy.satiation(), satiation); // Eggs don't get hungry
+
+    let mut family_tree = FamilyTree::default();
+    family_tree.add_birth(&puppy, &rex, &lady);
+
+    assert_eq!(family_tree.lineage("Puppy").unwrap().generation, 2);
+    assert_eq!(family_tree.children("Rex"), vec!["Puppy".to_string()]);
+}
+
+#[test]
+fn test_founders_have_the_traits_of_their_kind() 
+{
+    let mut monkey = Pet::new("Momo".to_string(), PetKind::Monkey);
+    let games_map = initialize_games();
+
+    assert_eq!(monkey.genes().traits, vec![Trait::Playful]);
+
+    monkey.set_quiet(true);
+    monkey.set_happiness(-5);
+    monkey.play("Swinging", &games_map, &Home::default());
+
+    assert_eq!(monkey.happiness(), 11); // The game's +5 and +1 for being playful
+}
@@ -0,0 +1,37 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::history::History;
+use tamagotchi::modules::social::Friendships;
+use tamagotchi::modules::simulation::Action;
+use tamagotchi::modules::journal::{EntryKind, JournalEntry};
+use tamagotchi::modules::locale::{set_locale, Locale};
+
+//The language is chosen once per test binary, so everything shown in German is checked here
+
+
+#[test]
+fn test_messages_are_shown_in_german()
+{
+    assert!(set_locale(Locale::German));
+
+    assert_eq!(History::default().undo(&[]).err(), Some("Es gibt nichts rückgängig zu machen.".to_string()));
+
+    let mut friendships = Friendships::default();
+    friendships.change("Rex", "Tom", 5);
+    assert_eq!(friendships.level("Rex", "Tom"), "Freunde");
+
+    assert_eq!(Action::Feed("Bone".to_string()).describe("Rex"), Some("Füttert Rex mit Knochen".to_string()));
+    assert_eq!(Action::Cook("Dog's dinner".to_string()).describe("Rex").unwrap(), "Kocht Hundeabendessen für Rex");
+}
+
+#[test]
+fn test_journal_is_written_in_german()
+{
+    assert!(set_locale(Locale::German));
+
+    let pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    let line = JournalEntry::new(EntryKind::Feed, "Füttern (Rex)".to_string(), &pet).to_line();
+
+    assert!(line.contains("Füttern  Füttern (Rex) (Leben 1, Gesundheit 10"), "{}", line);
+    assert_eq!(EntryKind::from_str("füttern"), Some(EntryKind::Feed)); // Filtering by the shown name
+    assert_eq!(EntryKind::from_str("Feed"), Some(EntryKind::Feed));
+}
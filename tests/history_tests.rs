@@ -0,0 +1,73 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::history::History;
+use tamagotchi::modules::session::Session;
+use tamagotchi::modules::locale::{set_locale, Locale};
+
+
+#[test]
+fn test_undo_last_feed() 
+{
+    assert!(set_locale(Locale::English));
+
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    let food_map = initialize_food();
+
+    let before = session.snapshot();
+    session.active_pet().feed("Bone", &food_map);
+    session.record_command("Feed (Rex)".to_string(), before);
+
+    assert_eq!(session.history().commands()[0].changes(), vec!["Rex: Energy 10 -> 11, Satiation 10 -> 12".to_string()]);
+    assert!(session.undo());
+    assert_eq!(session.active_pet().satiation(), 10);
+    assert!(!session.undo()); // Nothing left to undo
+}
+
+#[test]
+fn test_undo_expires_after_a_tick() 
+{
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    let food_map = initialize_food();
+
+    let before = session.snapshot();
+    session.active_pet().feed("Bone", &food_map);
+    session.record_command("Feed (Rex)".to_string(), before);
+
+    session.tick_all();
+
+    assert!(!session.history().commands()[0].is_undoable());
+    assert!(!session.undo());
+    assert_eq!(session.history().commands().len(), 1); // Still in the history
+}
+
+#[test]
+fn test_cannot_undo_past_a_lost_life() 
+{
+    let mut history = History::default();
+    let mut session = Session::with_seed(Pet::new("Polly".to_string(), PetKind::Parrot), 1);
+
+    let before = session.snapshot();
+    session.active_pet().set_health(-10);
+    session.active_pet().is_health_zero();
+    history.record("Play (Polly)".to_string(), before, session.snapshot());
+
+    assert!(history.undo(session.pets()).is_err());
+    assert_eq!(session.active_pet().lives(), 0);
+}
+
+#[test]
+fn test_undo_restores_the_random_numbers_and_the_lights() 
+{
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    session.set_quiet(true);
+
+    let before = session.snapshot();
+    let first_draw = session.rng().next_u64();
+    session.toggle_lights();
+    session.record_command("Toggle lights (Rex)".to_string(), before);
+
+    assert!(!session.home().lights_on);
+    assert!(session.undo());
+    assert!(session.home().lights_on);
+    assert_eq!(session.rng().next_u64(), first_draw); // The same number is drawn again
+}
@@ -0,0 +1,101 @@
+use std::env;
+use std::fs;
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::journal::{filter_entries, format_timestamp, page, EntryKind, Journal};
+use tamagotchi::modules::session::Session;
+
+
+fn test_journal(test_name: &str) -> (Journal, String)
+{
+    let directory = env::temp_dir().join(format!("tamagotchi_journal_{}", test_name));
+    let _ = fs::remove_dir_all(&directory);
+
+    let directory = directory.to_string_lossy().to_string();
+    (Journal::new(&directory), directory)
+}
+
+#[test]
+fn test_journal_appends_entries_with_stats()
+{
+    let (journal, directory) = test_journal("append");
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+
+    journal.write(&pet, EntryKind::Created, "Created".to_string());
+    pet.set_health(-3);
+    journal.write(&pet, EntryKind::Warning, "EnergyLow".to_string());
+
+    let entries = journal.read("Rex").unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].kind, EntryKind::Created);
+    assert_eq!(entries[1].health, 7);
+    assert!(journal.read("Nobody").unwrap().is_empty());
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn test_session_journals_actions_and_ticks()
+{
+    let (journal, directory) = test_journal("session");
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    session.add_pet(Pet::new("Tom".to_string(), PetKind::Cat));
+    session.set_journal(journal);
+    session.switch_to("Rex");
+
+    let before = session.snapshot();
+    session.active_pet().feed("Bone", &initialize_food());
+    session.journal_changes(EntryKind::Feed, "Feed (Rex)", &before.pets);
+    session.tick_all();
+
+    let rex = session.journal().read("Rex").unwrap();
+    let tom = session.journal().read("Tom").unwrap();
+    assert_eq!(rex.iter().map(|e| e.kind).collect::<Vec<_>>(), vec![EntryKind::Feed, EntryKind::Tick]);
+    assert_eq!(tom.iter().map(|e| e.kind).collect::<Vec<_>>(), vec![EntryKind::Tick]); // Tom was not fed
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn test_dead_pet_journal_is_put_aside()
+{
+    let (journal, directory) = test_journal("death");
+    let pet = Pet::new("Rex".to_string(), PetKind::Dog);
+
+    journal.write(&pet, EntryKind::Created, "Created".to_string());
+    journal.write_death(&pet);
+
+    assert!(journal.read("Rex").unwrap().is_empty()); // A new Rex starts from scratch
+
+    let retired = fs::read_dir(&directory).unwrap().count();
+    assert_eq!(retired, 1);
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn test_filtering_and_paging()
+{
+    let (journal, directory) = test_journal("filter");
+    let pet = Pet::new("Rex".to_string(), PetKind::Dog);
+
+    for i in 0..5
+    {
+        let kind = if i % 2 == 0 {EntryKind::Tick} else {EntryKind::Feed};
+        journal.write(&pet, kind, format!("Entry {}", i));
+    }
+
+    let entries = journal.read("Rex").unwrap();
+    let feeds = filter_entries(&entries, Some(EntryKind::Feed));
+    assert_eq!(feeds.iter().map(|e| e.message.as_str()).collect::<Vec<_>>(), vec!["Entry 3", "Entry 1"]); // Newest first
+
+    let all = filter_entries(&entries, None);
+    assert_eq!(page(&all, 0, 2).len(), 2);
+    assert_eq!(page(&all, 2, 2).len(), 1);
+    assert!(page(&all, 3, 2).is_empty());
+
+    assert_eq!(EntryKind::from_str("lifelost"), Some(EntryKind::LifeLost));
+    assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00:00");
+
+    let _ = fs::remove_dir_all(directory);
+}
@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+use tamagotchi::modules::locale::{catalog, fill, find_name, parse_catalog, translate, translate_name, Locale};
+use tamagotchi::modules::events::initialize_events;
+use tamagotchi::modules::recipes::initialize_recipes;
+
+
+//The parameters a message uses, e.g. {name}
+fn parameters(message: &str) -> BTreeSet<&str>
+{
+    message.split('{').skip(1).filter_map(|part| part.split_once('}')).map(|(name, _)| name).collect()
+}
+
+#[test]
+fn test_catalogs_match_english()
+{
+    let english = catalog(Locale::English);
+
+    for locale in Locale::ALL
+    {
+        let messages = catalog(locale);
+
+        for (id, message) in english
+        {
+            let translated = messages.get(id).unwrap_or_else(|| panic!("{} is missing {}", locale.code(), id));
+            assert_eq!(parameters(translated), parameters(message), "{} {}", locale.code(), id);
+        }
+
+        assert_eq!(messages.len(), english.len(), "{} has IDs English doesn't", locale.code());
+    }
+}
+
+#[test]
+fn test_translating_messages()
+{
+    assert_eq!(translate(Locale::German, "pet.lost_life", &[("name", "Rex".to_string()), ("lives", "2".to_string())]), "Rex hat ein Leben verloren! Verbleibende Leben: 2");
+    assert_eq!(translate(Locale::English, "no.such.message", &[]), "no.such.message");
+
+    assert_eq!(fill("{name} and {name} {unknown}", &[("name", "Rex".to_string())]), "Rex and Rex {unknown}");
+
+    assert!(parse_catalog("pet.fed = {name} was fed").is_ok());
+    assert!(parse_catalog("pet.fed {name} was fed").is_err());
+    assert!(parse_catalog("a = 1\na = 2").is_err());
+}
+
+#[test]
+fn test_choosing_the_language()
+{
+    let no_file = |name: &str| (name == "LANG").then(|| "de_DE.UTF-8".to_string());
+    assert_eq!(Locale::from_sources(None, no_file), Ok(Locale::German));
+
+    //The setting wins over the system locale and TAMAGOTCHI_LANG wins over both
+    assert_eq!(Locale::from_sources(Some(r#"{"language": "bg"}"#), no_file), Ok(Locale::Bulgarian));
+    assert_eq!(Locale::from_sources(Some(r#"{"language": "bg"}"#), |name| (name == "TAMAGOTCHI_LANG").then(|| "en".to_string())), Ok(Locale::English));
+
+    assert_eq!(Locale::from_sources(None, |name| (name == "LANG").then(|| "C.UTF-8".to_string())), Ok(Locale::English));
+    assert!(Locale::from_sources(Some(r#"{"language": "fr"}"#), |_| None).is_err());
+}
+
+#[test]
+fn test_names_are_stored_in_english()
+{
+    assert_eq!(translate_name(Locale::Bulgarian, "food", "Scooby snacks"), "Скуби бисквитки");
+    assert_eq!(translate_name(Locale::German, "game", "Hide and seek"), "Verstecken");
+    assert_eq!(translate_name(Locale::German, "food", "Mystery meat"), "Mystery meat");
+
+    let foods = vec!["Bone".to_string(), "Scooby snacks".to_string()];
+    let german = |food: &str| translate_name(Locale::German, "food", food);
+
+    assert_eq!(find_name("knochen", &foods, german), Some("Bone".to_string()));
+    assert_eq!(find_name(" scooby snacks ", &foods, german), Some("Scooby snacks".to_string()));
+    assert_eq!(find_name("Fisch", &foods, german), None);
+}
+
+#[test]
+fn test_every_event_is_translated()
+{
+    for event in initialize_events().events()
+    {
+        let id = event.name.to_lowercase().replace(' ', "_");
+
+        for locale in Locale::ALL
+        {
+            assert!(catalog(locale).contains_key(&format!("event.{}", id)), "{} is missing the event {}", locale.code(), event.name);
+            assert!(catalog(locale).contains_key(&format!("event_message.{}", id)), "{} is missing the message of {}", locale.code(), event.name);
+        }
+    }
+
+    assert_eq!(translate_name(Locale::German, "event", "Thunderstorm"), "Gewitter");
+}
+
+#[test]
+fn test_every_recipe_is_translated()
+{
+    for recipe in initialize_recipes().keys()
+    {
+        let id = recipe.to_lowercase().replace(' ', "_");
+
+        for locale in Locale::ALL
+        {
+            assert!(catalog(locale).contains_key(&format!("recipe.{}", id)), "{} is missing the recipe {}", locale.code(), recipe);
+        }
+    }
+
+    assert_eq!(translate_name(Locale::German, "recipe", "Dog's dinner"), "Hundeabendessen");
+}
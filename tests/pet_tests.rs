@@ -1,5 +1,6 @@
 
 use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::Home;
 use tamagotchi::modules::food::initialize_food;
 use tamagotchi::modules::games::initialize_games;
 
@@ -24,12 +25,13 @@ fn test_play_with_pet()
 {
     let mut pet = Pet::new("TestPet".to_string(), PetKind::Dog);
     let games_map = initialize_games();
+    let home = Home::default();
 
     assert_eq!(pet.happiness(), 10);
     assert_eq!(pet.energy(), 10);
     assert_eq!(pet.satiation(), 10);
 
-    pet.play("Fetch", &games_map);
+    pet.play("Fetch", &games_map, &home);
 
     assert_eq!(pet.happiness(), 14); // Fetch adds +4 happiness
     assert_eq!(pet.energy(), 6);     // Fetch costs -4 energy
@@ -45,7 +47,7 @@ fn test_leave_pet_alone()
     assert_eq!(pet.energy(), 10);
     assert_eq!(pet.happiness(), 10);
 
-    pet.leave_pet_alone(3);
+    pet.leave_pet_alone(3, &Home::default());
 
     assert_eq!(pet.satiation(), 7); // Satiation -3
     assert_eq!(pet.energy(), 7);    // Energy -3
@@ -69,4 +71,114 @@ fn test_health_and_life_loss()
 
     assert_eq!(pet.lives(), 0); 
     assert_eq!(pet.health(), 10); 
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_left_food_eaten_gradually() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Squirrel);
+    let food_map = initialize_food();
+
+    assert!(pet.leave_food("Nuts", &food_map));
+    assert!(!pet.leave_food("Bone", &food_map)); // Squirrels don't eat bones
+    assert_eq!(pet.satiation(), 10); // Nothing is eaten while leaving
+
+    pet.leave_pet_alone(4, &Home::default());
+
+    assert_eq!(pet.satiation(), 9); // -4 hours, +3 Nuts eaten when hungry at hour 3
+    assert_eq!(pet.energy(), 8);    // -4 hours, +2 Nuts
+    assert_eq!(pet.happiness(), 6); // Happiness -4
+    assert!(pet.bowl().is_empty());
+}
+
+#[test]
+fn test_spoiled_left_food_makes_pet_sick() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Cat);
+    let food_map = initialize_food();
+
+    pet.leave_food("Milk", &food_map);
+    pet.leave_pet_alone(5, &Home::default());
+
+    assert_eq!(pet.health(), 9);    // Milk spoiled after 2 hours and was eaten at hour 3
+    assert_eq!(pet.satiation(), 5); // No satiation from spoiled Milk
+    assert_eq!(pet.happiness(), 4); // Happiness -5 and -1 for being sick
+}
+
+#[test]
+fn test_balanced_diet_regenerates_health() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Rabbit);
+    let food_map = initialize_food();
+    let home = Home::default();
+
+    pet.set_health(-3);
+    pet.feed("Carrot", &food_map);
+    pet.feed("Spinach", &food_map);
+
+    assert_eq!(pet.health(), 7); // No protein yet
+
+    pet.feed("Seeds", &food_map);
+
+    assert_eq!(pet.health(), 8); // Balanced diet +1 health
+
+    pet.tick(&home);
+    pet.tick(&home);
+
+    assert_eq!(pet.health(), 8); // Only meals count, not the time passing
+}
+
+#[test]
+fn test_junk_food_diet_hurts_pet() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Monkey);
+    let food_map = initialize_food();
+
+    pet.feed("Cake", &food_map);
+    pet.feed("Popcorn", &food_map);
+    pet.feed("Cake", &food_map);
+
+    assert_eq!(pet.health(), 9);    // Treats only -1 health
+    assert_eq!(pet.happiness(), 9); // Treats only -1 happiness
+    assert_eq!(pet.diet().len(), 3);
+}
+
+#[test]
+fn test_sleep_recovers_energy_over_ticks() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Dog);
+    let home = Home::default();
+
+    pet.set_energy(-6);
+    pet.sleep();
+    assert!(pet.is_asleep());
+
+    pet.tick(&home);
+    pet.tick(&home);
+
+    assert!(pet.is_asleep());
+    assert_eq!(pet.energy(), 8);    // 4 + 2 per tick
+    assert_eq!(pet.satiation(), 8); // -1 per tick while asleep
+
+    pet.tick(&home);
+
+    assert!(!pet.is_asleep());       // Woke up by itself once rested
+    assert_eq!(pet.energy(), 10);
+    assert_eq!(pet.happiness(), 10); // Happiness +1 after a good sleep, capped at 10
+}
+
+#[test]
+fn test_waking_pet_early_makes_it_grumpy() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Cat);
+    let home = Home::default();
+
+    pet.set_energy(-6);
+    pet.sleep();
+    pet.tick(&home);
+    pet.wake_early();
+
+    assert!(!pet.is_asleep());
+    assert_eq!(pet.energy(), 7);    // Only one tick of rest, +1 for being lazy
+    assert_eq!(pet.happiness(), 8); // Grumpiness -2
+}
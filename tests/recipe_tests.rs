@@ -0,0 +1,80 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::recipes::{initialize_recipes, get_recipes_for_pet};
+use tamagotchi::modules::animation::KINDS;
+use tamagotchi::modules::clock::{GameClock, Home};
+
+
+#[test]
+fn test_recipe_combines_ingredients() 
+{
+    let food_map = initialize_food();
+    let recipes_map = initialize_recipes();
+
+    let biscuits = recipes_map["Homemade biscuits"].effect(&food_map).unwrap();
+
+    assert_eq!(biscuits.satiation_gain, 4); // Seeds +2, Raspberries +1, bonus +1
+    assert_eq!(biscuits.energy_gain, 2);    // Seeds +1, Raspberries +1
+    assert_eq!(biscuits.shelf_life, Some(4)); // Spoils as fast as the Raspberries
+}
+
+#[test]
+fn test_species_specific_recipes() 
+{
+    let recipes_map = initialize_recipes();
+
+    let dog_recipes = get_recipes_for_pet(&PetKind::Dog, &recipes_map);
+    assert!(dog_recipes.contains(&"Dog's dinner".to_string()));
+    assert!(!dog_recipes.contains(&"Homemade biscuits".to_string())); // Dogs don't eat seeds
+    assert!(!dog_recipes.contains(&"Trail mix".to_string()));
+
+    //Every recipe can be cooked for someone
+    for recipe in recipes_map.values() 
+    {
+        assert!(KINDS.iter().any(|kind| recipe.is_for(kind)), "nobody can eat {}", recipe.name);
+    }
+}
+
+#[test]
+fn test_cook_for_pet() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Dog);
+    let food_map = initialize_food();
+    let recipes_map = initialize_recipes();
+
+    pet.set_satiation(-5);
+
+    assert!(!pet.cook("Trail mix", &recipes_map, &food_map)); // Not a dog recipe
+    assert!(pet.cook("Dog's dinner", &recipes_map, &food_map));
+
+    assert_eq!(pet.satiation(), 11); // 5 + Steak 3 + Bone 2 + bonus 1
+    assert_eq!(pet.energy(), 13);    // 10 + Steak 1 + Bone 1 + bonus 1
+    assert_eq!(pet.pantry()["Steak"], 1); // The ingredients are used up
+
+    assert!(pet.cook("Dog's dinner", &recipes_map, &food_map));
+    assert!(!pet.cook("Dog's dinner", &recipes_map, &food_map)); // Nothing left until the morning
+}
+
+#[test]
+fn test_pantry_is_restocked_in_the_morning() 
+{
+    let mut pet = Pet::new("TestPet".to_string(), PetKind::Dog);
+    let food_map = initialize_food();
+    let recipes_map = initialize_recipes();
+    let mut home = Home { clock: GameClock::at(0, 22, 0), lights_on: true };
+    pet.set_quiet(true);
+
+    pet.cook("Dog's dinner", &recipes_map, &food_map);
+    pet.cook("Dog's dinner", &recipes_map, &food_map);
+    assert_eq!(pet.pantry()["Steak"], 0);
+
+    //Midnight is not the morning yet
+    while home.clock.hour() != 7 
+    {
+        home.clock.advance_minutes(GameClock::MINUTES_PER_TICK);
+        assert_eq!(pet.pantry()["Steak"], 0, "restocked at {}", home.clock);
+        pet.tick(&home);
+    }
+
+    assert_eq!(pet.pantry()["Steak"], 2);
+}
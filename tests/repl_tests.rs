@@ -0,0 +1,65 @@
+use tamagotchi::modules::locale::{set_locale, Locale};
+use tamagotchi::modules::repl::{fuzzy_find, parse_command, Action, Command};
+
+
+fn names(names: &[&str]) -> Vec<String>
+{
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_parse_commands()
+{
+    assert_eq!(parse_command("feed Scooby snacks").unwrap(), Some(Command { action: Action::Feed, argument: Some("Scooby snacks".to_string()) }));
+    assert_eq!(parse_command("  PLAY  ").unwrap(), Some(Command { action: Action::Play, argument: None }));
+    assert_eq!(parse_command("1").unwrap().unwrap().action, Action::Feed);
+    assert_eq!(parse_command("0").unwrap().unwrap().action, Action::Quit);
+    assert_eq!(parse_command("exit").unwrap().unwrap().action, Action::Quit);
+    assert_eq!(parse_command("").unwrap(), None);
+
+    //Typos
+    assert_eq!(parse_command("stauts").unwrap().unwrap().action, Action::Status);
+    assert_eq!(parse_command("fed bone").unwrap().unwrap().action, Action::Feed);
+}
+
+#[test]
+fn test_bad_commands()
+{
+    assert!(set_locale(Locale::English));
+
+    assert_eq!(parse_command("fly").unwrap_err(), "Unknown command 'fly'. Type 'help' for the list.");
+    assert_eq!(parse_command("s").unwrap_err(), "Did you mean: sleep, switch, skin, status?");
+    assert_eq!(parse_command("sleep now").unwrap_err(), "'sleep' doesn't take anything after it.");
+}
+
+#[test]
+fn test_fuzzy_find()
+{
+    let foods = names(&["Whiskas", "Scooby snacks", "Bone", "Biscuits"]);
+
+    assert_eq!(fuzzy_find("bone", &foods, |name| name.to_string()), Ok("Bone".to_string()));
+    assert_eq!(fuzzy_find("scoby", &foods, |name| name.to_string()), Ok("Scooby snacks".to_string()));
+    assert_eq!(fuzzy_find("snacks", &foods, |name| name.to_string()), Ok("Scooby snacks".to_string()));
+    assert_eq!(fuzzy_find("pizza", &foods, |name| name.to_string()), Err(Vec::new()));
+
+    //Both names start with "b"
+    assert_eq!(fuzzy_find("b", &foods, |name| name.to_string()), Err(names(&["Bone", "Biscuits"])));
+
+    //The translated name works too, but the English one is returned
+    let german = |name: &str| if name == "Bone" { "Knochen".to_string() } else { name.to_string() };
+    assert_eq!(fuzzy_find("knochn", &foods, german), Ok("Bone".to_string()));
+}
+
+#[test]
+fn test_menu_numbers()
+{
+    assert_eq!(Action::Feed.number(), Some(1));
+    assert_eq!(Action::Skin.number(), Some(19));
+    assert_eq!(Action::Quit.number(), Some(0));
+    assert_eq!(Action::Status.number(), None);
+
+    for action in Action::ALL
+    {
+        assert_eq!(parse_command(action.name()).unwrap().unwrap().action, action);
+    }
+}
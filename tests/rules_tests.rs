@@ -0,0 +1,85 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::rules::{Difficulty, Rules};
+
+
+#[test]
+fn test_classic_rules_file()
+{
+    //The shipped rule set documents the defaults
+    let classic = Rules::from_str(include_str!("../data/rules/classic.json")).unwrap();
+    assert_eq!(classic, Rules::default());
+}
+
+#[test]
+fn test_partial_rules()
+{
+    let rules = Rules::from_str(r#"{"lives": 3, "tick_seconds": 10}"#).unwrap();
+
+    assert_eq!(rules.lives, 3);
+    assert_eq!(rules.tick().as_secs(), 10);
+    assert_eq!(rules.max_health, Rules::default().max_health);
+}
+
+#[test]
+fn test_invalid_rules()
+{
+    //Typos and broken values are reported instead of being ignored
+    assert!(Rules::from_str(r#"{"live": 3}"#).is_err());
+    assert!(Rules::from_str(r#"{"lives": -1}"#).is_err());
+
+    assert_eq!(Rules::from_str(r#"{"lives": 0}"#).unwrap_err(), "lives must be at least 1");
+    assert_eq!(Rules::from_str(r#"{"tired_energy": 10}"#).unwrap_err(), "tired_energy must be below full_stats");
+    assert_eq!(Rules::from_str(r#"{"satiation_high": 16}"#).unwrap_err(), "satiation_high can't be above satiation_high_max");
+
+    //Values that would overflow when they are taken off a stat
+    assert!(Rules::from_str(r#"{"decay": 128}"#).is_err());
+    assert!(Rules::from_str(r#"{"grumpiness": 200}"#).is_err());
+    assert!(Rules::from_str(r#"{"sleep_satiation_cost": 255}"#).is_err());
+}
+
+#[test]
+fn test_difficulties()
+{
+    let classic = Rules::default();
+    assert_eq!(classic.for_difficulty(Difficulty::Classic), classic);
+    assert_eq!(classic.for_difficulty(Difficulty::Easy).lives, 3);
+    assert!(!classic.for_difficulty(Difficulty::Hardcore).sleep_cheers_up);
+    assert_eq!(classic.for_difficulty(Difficulty::Hardcore).wake_energy, 8); // Halfway from 5 to 10
+    assert_eq!(classic.for_difficulty(Difficulty::Hardcore).sleep_energy_gain, 1); // Half of 2
+
+    //Two ticks: easy pets lose 1 happiness, classic ones 2 and hardcore ones 4
+    let happiness_after_two_ticks = |difficulty: Difficulty| {
+        let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+        pet.set_difficulty(difficulty);
+        pet.tick(&Home::default());
+        pet.tick(&Home::default());
+        pet.happiness()
+    };
+
+    assert_eq!(happiness_after_two_ticks(Difficulty::Easy), 9);
+    assert_eq!(happiness_after_two_ticks(Difficulty::Classic), 8);
+    assert_eq!(happiness_after_two_ticks(Difficulty::Hardcore), 6);
+}
+
+#[test]
+fn test_hardcore_pets_wake_up_before_they_are_rested()
+{
+    //A tired pet sleeps until it wakes up by itself
+    let energy_after_sleep = |difficulty: Difficulty| {
+        let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+        pet.set_quiet(true);
+        pet.set_difficulty(difficulty);
+        pet.set_energy(-5);
+        pet.sleep();
+
+        while pet.is_asleep()
+        {
+            pet.tick(&Home::default());
+        }
+        pet.energy()
+    };
+
+    assert_eq!(energy_after_sleep(Difficulty::Classic), 11);
+    assert_eq!(energy_after_sleep(Difficulty::Hardcore), 8);
+}
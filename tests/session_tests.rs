@@ -0,0 +1,83 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::session::Session;
+
+
+#[test]
+fn test_session_with_several_pets() 
+{
+    let mut session = Session::new(Pet::new("Rex".to_string(), PetKind::Dog));
+
+    assert!(session.add_pet(Pet::new("Tom".to_string(), PetKind::Cat)));
+    assert!(!session.add_pet(Pet::new("Rex".to_string(), PetKind::Dog))); // Already in the session
+    assert_eq!(session.pets().len(), 2);
+    assert_eq!(session.active_pet().name(), "Tom"); // Newly added pet becomes active
+
+    assert!(session.switch_to("Rex"));
+    assert!(!session.switch_to("Nobody"));
+    assert_eq!(session.active_pet().name(), "Rex");
+}
+
+#[test]
+fn test_tick_decays_every_pet() 
+{
+    //No random event happens on the first tick with this seed
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 1);
+    session.add_pet(Pet::new("Tom".to_string(), PetKind::Cat));
+
+    session.tick_all();
+
+    assert!(session.log().is_empty());
+
+    for pet in session.pets() 
+    {
+        assert_eq!(pet.happiness(), 9);
+        assert_eq!(pet.energy(), 9);
+        assert_eq!(pet.satiation(), 9);
+    }
+}
+
+#[test]
+fn test_dead_pets_leave_the_session() 
+{
+    let mut session = Session::new(Pet::new("SessionGhost".to_string(), PetKind::Parrot));
+    session.add_pet(Pet::new("Tom".to_string(), PetKind::Cat));
+    session.switch_to("SessionGhost");
+
+    let ghost = session.active_pet();
+    ghost.set_health(-10);
+    ghost.is_health_zero();
+
+    assert_eq!(session.remove_dead(), vec!["SessionGhost".to_string()]);
+    assert_eq!(session.pets().len(), 1);
+    assert_eq!(session.active_pet().name(), "Tom");
+}
+
+#[test]
+fn test_session_rng_is_resumed_from_the_pet() 
+{
+    let mut session = Session::with_seed(Pet::new("Rex".to_string(), PetKind::Dog), 42);
+    assert_eq!(session.rng().seed(), 42);
+    session.rng().next_u64();
+
+    //The pet is saved with the session's random sequence
+    let mut pet = session.active_pet().clone();
+    pet.set_rng(session.rng().clone());
+    let expected = session.rng().next_u64();
+
+    let saved = serde_json::to_string(&pet).unwrap();
+    let loaded: Pet = serde_json::from_str(&saved).unwrap();
+
+    //Loading the pet on its own continues the same random sequence
+    let rng = loaded.rng().cloned().expect("the random sequence is saved with the pet");
+    let mut resumed = Session::with_rng(loaded, rng);
+    assert_eq!(resumed.rng().seed(), 42);
+    assert_eq!(resumed.rng().draws(), 1);
+    assert_eq!(resumed.rng().next_u64(), expected);
+}
+
+#[test]
+fn test_pet_without_saved_rng() 
+{
+    let pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    assert!(pet.rng().is_none());
+}
@@ -0,0 +1,72 @@
+use tamagotchi::modules::pet::{Pet, PetKind, Warning};
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::games::initialize_games;
+use tamagotchi::modules::recipes::initialize_recipes;
+use tamagotchi::modules::simulation::{Action, Simulation};
+
+
+#[test]
+fn test_neglected_pet_dies() 
+{
+    let food_map = initialize_food();
+    let games_map = initialize_games();
+    let recipes_map = initialize_recipes();
+    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
+    let report = simulation.run_script(Pet::new("Sim".to_string(), PetKind::Dog), 500, &[]);
+
+    assert!(report.time_to_death.is_some());
+    assert_eq!(report.lives_lost, 1);
+    assert!(report.warnings[&Warning::EnergyLow] > 0);
+    assert!(report.warnings[&Warning::SatiationEmpty] > 0);
+}
+
+#[test]
+fn test_scripted_care_and_report() 
+{
+    let food_map = initialize_food();
+    let games_map = initialize_games();
+    let recipes_map = initialize_recipes();
+    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
+    let script = vec![
+        Action::Feed("Bone".to_string()),
+        Action::Play("Fetch".to_string()),
+        Action::Feed("Unknown food".to_string()),
+        Action::Wait,
+    ];
+
+    let report = simulation.run_script(Pet::new("Sim".to_string(), PetKind::Dog), 4, &script);
+
+    assert_eq!(report.ticks_run, 4);
+    assert_eq!(report.failed_actions, 1);
+    assert_eq!(report.time_to_death, None);
+    assert!(report.average_health > 9.0);
+}
+
+#[test]
+fn test_policy_keeps_pet_alive_longer() 
+{
+    let food_map = initialize_food();
+    let games_map = initialize_games();
+    let recipes_map = initialize_recipes();
+    let simulation = Simulation { food_map: &food_map, games_map: &games_map, recipes_map: &recipes_map };
+
+    let report = simulation.run(Pet::new("Sim".to_string(), PetKind::Squirrel), 500, |pet, _, _| {
+        if pet.is_tired() && !pet.is_asleep() 
+        {
+            Action::Sleep
+        } 
+        else if pet.satiation() < 8 
+        {
+            Action::Feed("Nuts".to_string())
+        } 
+        else 
+        {
+            Action::Wait
+        }
+    });
+
+    assert_eq!(report.lives_lost, 0);
+    assert_eq!(report.ticks_run, 500);
+}
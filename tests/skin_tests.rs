@@ -0,0 +1,78 @@
+use std::env;
+use std::fs;
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::animation::{frames, Animation, KINDS};
+use tamagotchi::modules::skins::{skin_frames, validate_frame, Skin};
+
+
+fn skins_directory(test_name: &str) -> String 
+{
+    let directory = env::temp_dir().join(format!("tamagotchi_skins_{}", test_name));
+    let _ = fs::remove_dir_all(&directory);
+    directory.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_built_in_art_is_valid() 
+{
+    for kind in KINDS 
+    {
+        for animation in Animation::ALL 
+        {
+            for frame in frames(&kind, animation) 
+            {
+                assert_eq!(validate_frame(frame), Ok(()), "{:?} {:?}", kind, animation);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_loading_a_skin() 
+{
+    let directory = skins_directory("good");
+    fs::create_dir_all(format!("{}/neon/dog", directory)).unwrap();
+    fs::write(format!("{}/neon/dog/idle.txt", directory), " (o.o)\n---\n (-.-)\n").unwrap();
+
+    let skin = Skin::load(&directory, "neon").unwrap();
+    assert_eq!(skin.frames(&PetKind::Dog, Animation::Idle).unwrap(), [" (o.o)".to_string(), " (-.-)".to_string()]);
+    assert!(skin.frames(&PetKind::Dog, Animation::Sad).is_none()); // Left to the built-in art
+    assert!(skin.frames(&PetKind::Cat, Animation::Idle).is_none());
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn test_invalid_skins_are_rejected() 
+{
+    let directory = skins_directory("bad");
+    fs::create_dir_all(format!("{}/broken/dragon", directory)).unwrap();
+    fs::create_dir_all(format!("{}/broken/cat", directory)).unwrap();
+    fs::write(format!("{}/broken/cat/idle.txt", directory), "x".repeat(60)).unwrap();
+    fs::write(format!("{}/broken/cat/sad.txt", directory), "\\\"meow\\\"").unwrap();
+    fs::write(format!("{}/broken/cat/dancing.txt", directory), "o").unwrap();
+
+    let errors = Skin::load(&directory, "broken").err().unwrap();
+    assert_eq!(errors.len(), 4); // Unknown species, too wide, escaped, unknown animation
+    assert!(Skin::load(&directory, "missing").is_err());
+    assert!(Skin::load(&directory, "../broken").is_err());
+    assert!(Skin::load(&directory, "..").is_err());
+
+    assert!(validate_frame(&"o\n".repeat(20)).is_err()); // Too high
+    assert!(validate_frame("\x1b[31m(o.o)").is_err());
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn test_skin_is_saved_with_the_pet() 
+{
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    assert_eq!(&pet.art(Animation::Idle)[..], frames(&PetKind::Dog, Animation::Idle));
+
+    pet.set_skin(Some("no such skin".to_string()));
+    let loaded: Pet = serde_json::from_str(&serde_json::to_string(&pet).unwrap()).unwrap();
+
+    assert_eq!(loaded.skin(), Some("no such skin"));
+    assert_eq!(&skin_frames(loaded.skin(), &PetKind::Dog, Animation::Idle)[..], frames(&PetKind::Dog, Animation::Idle)); // Falls back
+}
@@ -0,0 +1,88 @@
+use tamagotchi::modules::pet::{Pet, PetKind};
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::games::initialize_games;
+use tamagotchi::modules::social::{compatibility, interact, Friendships, Interaction};
+use tamagotchi::modules::locale::{set_locale, Locale};
+
+
+#[test]
+fn test_compatibility_between_kinds() 
+{
+    assert_eq!(compatibility(&PetKind::Cat, &PetKind::Parrot), -3);
+    assert_eq!(compatibility(&PetKind::Parrot, &PetKind::Cat), -3);
+    assert_eq!(compatibility(&PetKind::Dog, &PetKind::Dog), 2);
+    assert_eq!(compatibility(&PetKind::Axalotl, &PetKind::Monkey), 0);
+}
+
+#[test]
+fn test_friendship_is_shared_by_the_pair() 
+{
+    assert!(set_locale(Locale::English));
+
+    let mut friendships = Friendships::default();
+
+    friendships.change("Rex", "Tom", 3);
+    friendships.change("Tom", "Rex", 3);
+
+    assert_eq!(friendships.score("Rex", "Tom"), 6);
+    assert_eq!(friendships.level("Tom", "Rex"), "Friends");
+
+    friendships.change("Rex", "Tom", 20);
+    assert_eq!(friendships.score("Rex", "Tom"), 10); // Capped at 10
+
+    friendships.change("Rex", "Tom", i8::MAX);
+    assert_eq!(friendships.score("Rex", "Tom"), 10);
+}
+
+#[test]
+fn test_friendships_survive_saving() 
+{
+    let mut friendships = Friendships::default();
+
+    //Pairs that would share a key if the names were joined with |
+    friendships.change("A|B", "C", 4);
+    friendships.change("A", "B|C", -4);
+
+    let saved: Friendships = serde_json::from_str(&serde_json::to_string(&friendships).unwrap()).unwrap();
+
+    assert_eq!(saved.score("C", "A|B"), 4);
+    assert_eq!(saved.score("A", "B|C"), -4);
+}
+
+#[test]
+fn test_playing_together() 
+{
+    let mut rex = Pet::new("Rex".to_string(), PetKind::Dog);
+    let mut max = Pet::new("Max".to_string(), PetKind::Dog);
+    let games_map = initialize_games();
+    let mut friendships = Friendships::default();
+
+    assert!(!interact(&mut rex, &mut max, &Interaction::PlayTogether("Fetch".to_string()), &games_map, &Home::default(), &mut friendships)); // Not multiplayer
+    assert!(interact(&mut rex, &mut max, &Interaction::PlayTogether("Playing with ball".to_string()), &games_map, &Home::default(), &mut friendships));
+
+    assert_eq!(rex.happiness(), 15); // 10 + ball 4 + same kind 2, capped at 15
+    assert_eq!(max.energy(), 6);     // Ball costs 4 energy
+    assert_eq!(friendships.score("Rex", "Max"), 3);
+}
+
+#[test]
+fn test_incompatible_pets_fall_out() 
+{
+    assert!(set_locale(Locale::English));
+
+    let mut tom = Pet::new("Tom".to_string(), PetKind::Cat);
+    let mut polly = Pet::new("Polly".to_string(), PetKind::Parrot);
+    let games_map = initialize_games();
+    let mut friendships = Friendships::default();
+
+    interact(&mut tom, &mut polly, &Interaction::Groom, &games_map, &Home::default(), &mut friendships);
+
+    assert_eq!(tom.happiness(), 8);   // 1 - 3 for Cat and Parrot
+    assert_eq!(polly.happiness(), 8);
+    assert_eq!(friendships.score("Tom", "Polly"), -1);
+
+    interact(&mut tom, &mut polly, &Interaction::FightOverFood, &games_map, &Home::default(), &mut friendships);
+
+    assert_eq!(friendships.level("Tom", "Polly"), "Wary");
+    assert_eq!(tom.health() + polly.health(), 19); // One of them got hurt
+}
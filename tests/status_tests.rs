@@ -0,0 +1,57 @@
+use serde_json::Value;
+use tamagotchi::modules::pet::{Mood, Pet, PetKind, Warning};
+use tamagotchi::modules::food::initialize_food;
+use tamagotchi::modules::clock::Home;
+use tamagotchi::modules::status::{status_json, ActionResult, SCHEMA_VERSION};
+
+
+#[test]
+fn test_status_json_schema() 
+{
+    let pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    let status: Value = serde_json::from_str(&status_json(&pet, &Home::default(), None)).unwrap();
+
+    //Exactly the documented fields
+    let fields: Vec<&str> = status.as_object().unwrap().keys().map(|key| key.as_str()).collect();
+    let mut expected = vec![
+        "schema_version", "name", "kind", "difficulty", "egg", "asleep", "age_minutes", "clock", "lights_on",
+        "lives", "health", "happiness", "energy", "satiation", "mood", "warnings", "last_action",
+    ];
+    expected.sort();
+    assert_eq!(fields, expected); // serde_json sorts the keys of a Value
+
+    assert_eq!(status["schema_version"], SCHEMA_VERSION);
+    assert_eq!(status["kind"], "Dog");
+    assert_eq!(status["difficulty"], "Classic");
+    assert_eq!(status["clock"], serde_json::json!({"day": 0, "hour": 8, "minute": 0, "phase": "Day"}));
+    assert_eq!(status["mood"], "Happy");
+    assert_eq!(status["warnings"], serde_json::json!([]));
+    assert!(status["last_action"].is_null());
+}
+
+#[test]
+fn test_status_json_with_last_action() 
+{
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    pet.set_quiet(true);
+    let success = pet.feed("Bone", &initialize_food());
+
+    let last_action = ActionResult { action: "feed".to_string(), target: Some("Bone".to_string()), success };
+    let status: Value = serde_json::from_str(&status_json(&pet, &Home::default(), Some(last_action))).unwrap();
+
+    assert_eq!(status["satiation"], 12);
+    assert_eq!(status["warnings"], serde_json::json!(["SatiationHigh"]));
+    assert_eq!(status["last_action"], serde_json::json!({"action": "feed", "target": "Bone", "success": true}));
+}
+
+#[test]
+fn test_mood_and_warnings_have_no_side_effects() 
+{
+    let mut pet = Pet::new("Rex".to_string(), PetKind::Dog);
+    pet.set_energy(-10);
+    pet.set_satiation(-8);
+
+    assert_eq!(pet.mood(), Mood::Hungry);
+    assert_eq!(pet.warnings(), vec![Warning::EnergyEmpty]);
+    assert_eq!(pet.health(), 10); // Unlike check_energy, no health is lost
+}
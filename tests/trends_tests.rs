@@ -0,0 +1,55 @@
+use tamagotchi::modules::journal::{EntryKind, JournalEntry};
+use tamagotchi::modules::display::DisplayMode;
+use tamagotchi::modules::trends::{markers, render_trends, sparkline, Span};
+
+
+fn entry(timestamp: u64, kind: EntryKind, health: u8) -> JournalEntry
+{
+    JournalEntry
+    {
+        timestamp,
+        kind,
+        message: String::new(),
+        lives: 3,
+        health,
+        happiness: 10,
+        energy: 10,
+        satiation: 10,
+    }
+}
+
+#[test]
+fn test_sparkline_carries_values_forward()
+{
+    //An hour split into 4 columns of 15 minutes
+    let entries = vec![entry(1_000, EntryKind::Tick, 10), entry(2_000, EntryKind::Tick, 0)];
+    let now = 3_600;
+
+    assert_eq!(sparkline(&entries, |e| e.health, now, Span::Hour, 4, DisplayMode::Fancy), " █▁▁");
+    assert_eq!(sparkline(&entries, |e| e.health, now, Span::Hour, 4, DisplayMode::Plain), " #__");
+}
+
+#[test]
+fn test_old_entries_are_carried_in()
+{
+    let entries = vec![entry(0, EntryKind::Tick, 0), entry(50, EntryKind::Tick, 10), entry(Span::Day.seconds() + 100, EntryKind::Tick, 0)];
+    let now = Span::Day.seconds() + 50;
+
+    assert_eq!(sparkline(&entries, |e| e.health, now, Span::Day, 3, DisplayMode::Fancy), "███"); // Health was 10 when the day began
+    assert_eq!(markers(&entries, now, Span::Day, 0), "");
+    assert_eq!(sparkline(&entries, |e| e.health, now, Span::Day, 0, DisplayMode::Fancy), "");
+}
+
+#[test]
+fn test_warnings_and_lost_lives_are_marked()
+{
+    let entries = vec![
+        entry(100, EntryKind::Warning, 5),
+        entry(1_000, EntryKind::Warning, 1),
+        entry(1_100, EntryKind::LifeLost, 10),
+        entry(3_000, EntryKind::Feed, 10),
+    ];
+
+    assert_eq!(markers(&entries, 3_600, Span::Hour, 4), "!X  ");
+    assert_eq!(render_trends(&entries, 3_600, Span::Hour, 4, DisplayMode::Fancy).len(), 5);
+}